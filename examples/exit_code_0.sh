@@ -1 +1,3 @@
+#!/bin/sh
+
 exit 0
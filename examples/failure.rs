@@ -1,8 +1,6 @@
-use proc_result::ProcResult;
+use proc_result::command::CommandExt;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let status = std::process::Command::new("examples/exit_code_1.sh").status()?;
-    let result: ProcResult = status.into();
-    result.ok()?;
+    std::process::Command::new("examples/exit_code_1.sh").status_result()?;
     Ok(())
 }
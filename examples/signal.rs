@@ -1,7 +1,8 @@
-use proc_result::ProcResult;
+use proc_result::command::ChildExt;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut child = std::process::Command::new("examples/hang_forever.sh").spawn()?;
+    let mut command = std::process::Command::new("examples/hang_forever.sh");
+    let mut child = command.spawn()?;
     let pid = child.id();
 
     // Spawn a thread, and in 1s, use the PID to kill the process (not the child handle).
@@ -15,8 +16,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     println!("Waiting for process to end");
-    let result: ProcResult = child.wait()?.into();
-    result.ok()?;
+    child.wait_result(&command)?;
 
     // Will never happen.
     println!("Done!");
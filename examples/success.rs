@@ -1,9 +1,6 @@
-use proc_result::ProcResult;
+use proc_result::command::CommandExt;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let result: ProcResult = std::process::Command::new("examples/exit_code_0.sh")
-        .status()?
-        .into();
-    result.ok()?;
+    std::process::Command::new("examples/exit_code_0.sh").status_result()?;
     Ok(())
 }
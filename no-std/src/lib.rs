@@ -0,0 +1,145 @@
+//! Checks that `proc-result` builds without `std`, and without `alloc` unless it is enabled.
+//!
+//! This crate is built for a target without `std` in CI, i.e.:
+//!
+//! ```sh
+//! cargo build -p proc-result-no-std --target thumbv7em-none-eabihf
+//! cargo build -p proc-result-no-std --target thumbv7em-none-eabihf --features alloc
+//! ```
+#![no_std]
+
+use core::error::Error;
+use core::fmt::{self, Write};
+
+use proc_result::raw::RawExitCode;
+use proc_result::{Outcome, ProcResult, ResultFormat, unix, windows};
+
+/// A fixed-size buffer that results are formatted into, without allocating.
+pub struct Buffer {
+    bytes: [u8; 128],
+    len: usize,
+}
+
+impl Buffer {
+    /// Creates an empty buffer.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; 128],
+            len: 0,
+        }
+    }
+
+    /// Returns the formatted text.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Describes a Unix wait status, i.e. `killed by signal SIGKILL (9)`.
+///
+/// # Errors
+///
+/// If the description does not fit in the buffer.
+pub fn describe(status: i32, buffer: &mut Buffer) -> fmt::Result {
+    write!(
+        buffer,
+        "{}",
+        ProcResult::Unix(unix::WaitStatus::from_raw(status))
+    )
+}
+
+/// Formats a Windows exit code in a [`ResultFormat`].
+///
+/// # Errors
+///
+/// If the result does not fit in the buffer.
+pub fn format(code: u32, format: ResultFormat, buffer: &mut Buffer) -> fmt::Result {
+    let result = ProcResult::Windows(windows::ExitCode::from_raw(code));
+    write!(buffer, "{}", result.format(format))
+}
+
+/// Parses a result in its canonical form, and classifies it.
+#[must_use]
+pub fn outcome(canonical: &str) -> Option<Outcome> {
+    canonical.parse::<ProcResult>().ok().map(|r| r.outcome())
+}
+
+/// Returns the raw value of an exit code through [`RawExitCode`].
+#[must_use]
+pub fn raw<T: RawExitCode>(code: &T) -> T::Code {
+    code.to_raw()
+}
+
+/// Returns a result as an error.
+#[must_use]
+pub fn as_error(result: &ProcResult) -> &dyn Error {
+    result
+}
+
+/// Returns whether a result is expected by a spec, i.e. `0,1,!SIGKILL`.
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn matches(spec: &str, result: &ProcResult) -> Option<bool> {
+    let expected = spec
+        .parse::<proc_result::expected::ExpectedOutcome>()
+        .ok()?;
+    Some(expected.matches(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let mut buffer = Buffer::new();
+        describe(9, &mut buffer).unwrap();
+        assert_eq!(buffer.as_str(), "killed by signal SIGKILL (9)");
+    }
+
+    #[test]
+    fn test_format() {
+        let mut buffer = Buffer::new();
+        format(0xC000_0005, ResultFormat::Canonical, &mut buffer).unwrap();
+        assert_eq!(buffer.as_str(), "windows:0xC0000005");
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(outcome("unix:signal:SIGKILL"), Some(Outcome::Killed));
+        assert_eq!(outcome("unix:exit"), None);
+    }
+
+    #[test]
+    fn test_raw() {
+        assert_eq!(raw(&unix::ExitCode::GENERAL_ERROR), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_matches() {
+        let result = ProcResult::Unix(unix::WaitStatus::from_raw(0x0100));
+        assert_eq!(matches("0-1,!SIGKILL", &result), Some(true));
+        assert_eq!(matches("0,SIGFOO", &result), None);
+    }
+}
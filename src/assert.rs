@@ -0,0 +1,356 @@
+//! Support for the assertion macros, i.e. [`assert_success!`](crate::assert_success).
+//!
+//! This module is not public API; it may change without notice.
+
+use std::fmt;
+
+use crate::ProcResult;
+use crate::error::Excerpt;
+use crate::unix::Signal;
+
+/// A value that the assertion macros can check.
+pub trait Subject {
+    /// Returns the result to check.
+    fn result(&self) -> ProcResult;
+
+    /// Returns the captured `stderr` of the process, if any.
+    fn stderr(&self) -> Option<&[u8]> {
+        None
+    }
+}
+
+impl<T: Subject + ?Sized> Subject for &T {
+    fn result(&self) -> ProcResult {
+        (**self).result()
+    }
+
+    fn stderr(&self) -> Option<&[u8]> {
+        (**self).stderr()
+    }
+}
+
+impl Subject for ProcResult {
+    fn result(&self) -> ProcResult {
+        *self
+    }
+}
+
+impl Subject for crate::unix::WaitStatus {
+    fn result(&self) -> ProcResult {
+        ProcResult::Unix(*self)
+    }
+}
+
+impl Subject for crate::windows::ExitCode {
+    fn result(&self) -> ProcResult {
+        ProcResult::Windows(*self)
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl Subject for std::process::ExitStatus {
+    fn result(&self) -> ProcResult {
+        ProcResult::from(*self)
+    }
+}
+
+#[cfg(any(unix, windows))]
+impl Subject for std::process::Output {
+    fn result(&self) -> ProcResult {
+        ProcResult::from(self.status)
+    }
+
+    fn stderr(&self) -> Option<&[u8]> {
+        Some(&self.stderr)
+    }
+}
+
+/// Returns the exit code of the result, on either platform.
+#[must_use]
+pub fn exit_code(result: &ProcResult) -> Option<u32> {
+    match result {
+        ProcResult::Unix(status) => status.exit_code().map(|code| code.to_raw().into()),
+        ProcResult::Windows(code) => Some(code.to_raw()),
+        _ => None,
+    }
+}
+
+/// Returns the signal that terminated the process, if any.
+#[must_use]
+pub fn signal(result: &ProcResult) -> Option<Signal> {
+    match result {
+        ProcResult::Unix(status) => status.signal(),
+        _ => None,
+    }
+}
+
+/// Panics with the decoded result and `stderr` of `subject`, unless `predicate` holds.
+///
+/// # Panics
+///
+/// If `predicate` returns `false` for the result of `subject`.
+#[track_caller]
+pub fn check<S: Subject + ?Sized>(
+    subject: &S,
+    predicate: impl FnOnce(&ProcResult) -> bool,
+    expected: fmt::Arguments<'_>,
+    message: Option<fmt::Arguments<'_>>,
+) {
+    let result = subject.result();
+    if predicate(&result) {
+        return;
+    }
+    panic!(
+        "{}",
+        Failure {
+            expected,
+            message,
+            result,
+            stderr: subject.stderr().map(Excerpt::new),
+        }
+    );
+}
+
+/// The panic message of a failed assertion.
+struct Failure<'a> {
+    expected: fmt::Arguments<'a>,
+    message: Option<fmt::Arguments<'a>>,
+    result: ProcResult,
+    stderr: Option<Excerpt>,
+}
+
+impl fmt::Display for Failure<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "assertion failed: expected {}", self.expected)?;
+        if let Some(message) = self.message {
+            write!(f, ": {message}")?;
+        }
+        let result = self.result;
+        write!(f, "\n  result: {result}\n     raw: {result:?}")?;
+        match &self.stderr {
+            Some(stderr) if !stderr.is_empty() => write!(f, "\n  stderr:\n{stderr}"),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Asserts that a process succeeded.
+///
+/// Accepts a [`ProcResult`], [`unix::WaitStatus`], [`windows::ExitCode`],
+/// [`std::process::ExitStatus`] or [`std::process::Output`]. On failure, the panic message
+/// includes the decoded result and, for an `Output`, the end of its `stderr`:
+///
+/// ```text
+/// assertion failed: expected success
+///   result: exited with code 1
+///      raw: Unix(WaitStatus(256))
+///   stderr:
+///     error: no such file
+/// ```
+///
+/// Like [`assert!`], a custom message can follow the result.
+///
+/// ```rust
+/// use proc_result::{ProcResult, assert_success};
+/// use proc_result::unix::WaitStatus;
+///
+/// assert_success!(ProcResult::Unix(WaitStatus::from_raw(0)));
+/// assert_success!(WaitStatus::from_raw(0), "build of {} failed", "crate");
+/// ```
+///
+/// [`unix::WaitStatus`]: crate::unix::WaitStatus
+/// [`windows::ExitCode`]: crate::windows::ExitCode
+#[macro_export]
+macro_rules! assert_success {
+    ($subject:expr $(,)?) => {
+        $crate::assert::check(
+            &$subject,
+            $crate::ProcResult::is_success,
+            ::core::format_args!("success"),
+            ::core::option::Option::None,
+        )
+    };
+    ($subject:expr, $($arg:tt)+) => {
+        $crate::assert::check(
+            &$subject,
+            $crate::ProcResult::is_success,
+            ::core::format_args!("success"),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+/// Asserts that a process exited with an exit code, on either platform.
+///
+/// Accepts the same values as [`assert_success!`](crate::assert_success), and prints the same
+/// details on failure.
+///
+/// ```rust
+/// use proc_result::assert_exit_code;
+/// use proc_result::unix::WaitStatus;
+/// use proc_result::windows::ExitCode;
+///
+/// assert_exit_code!(WaitStatus::from_raw(0x0200), 2);
+/// assert_exit_code!(ExitCode::from_raw(0xC000_0005), 0xC000_0005, "expected a crash");
+/// ```
+#[macro_export]
+macro_rules! assert_exit_code {
+    ($subject:expr, $code:expr $(,)?) => {{
+        let code: u32 = $code;
+        $crate::assert::check(
+            &$subject,
+            |result| $crate::assert::exit_code(result) == ::core::option::Option::Some(code),
+            ::core::format_args!("exit code {}", code),
+            ::core::option::Option::None,
+        )
+    }};
+    ($subject:expr, $code:expr, $($arg:tt)+) => {{
+        let code: u32 = $code;
+        $crate::assert::check(
+            &$subject,
+            |result| $crate::assert::exit_code(result) == ::core::option::Option::Some(code),
+            ::core::format_args!("exit code {}", code),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    }};
+}
+
+/// Asserts that a process was terminated by a Unix signal, or by a specific one.
+///
+/// Accepts the same values as [`assert_success!`](crate::assert_success), and prints the same
+/// details on failure.
+///
+/// ```rust
+/// use proc_result::assert_signaled;
+/// use proc_result::unix::{Signal, WaitStatus};
+///
+/// assert_signaled!(WaitStatus::from_raw(9));
+/// assert_signaled!(WaitStatus::from_raw(9), Signal::KILL);
+/// assert_signaled!(WaitStatus::from_raw(9), Signal::KILL, "expected the OOM killer");
+/// ```
+#[macro_export]
+macro_rules! assert_signaled {
+    ($subject:expr $(,)?) => {
+        $crate::assert::check(
+            &$subject,
+            |result| $crate::assert::signal(result).is_some(),
+            ::core::format_args!("termination by a signal"),
+            ::core::option::Option::None,
+        )
+    };
+    ($subject:expr, $signal:expr $(,)?) => {{
+        let signal: $crate::unix::Signal = $signal;
+        $crate::assert::check(
+            &$subject,
+            |result| $crate::assert::signal(result) == ::core::option::Option::Some(signal),
+            ::core::format_args!("termination by {}", $crate::expected::Pattern::Signal(signal)),
+            ::core::option::Option::None,
+        )
+    }};
+    ($subject:expr, $signal:expr, $($arg:tt)+) => {{
+        let signal: $crate::unix::Signal = $signal;
+        $crate::assert::check(
+            &$subject,
+            |result| $crate::assert::signal(result) == ::core::option::Option::Some(signal),
+            ::core::format_args!("termination by {}", $crate::expected::Pattern::Signal(signal)),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    }};
+}
+
+/// Asserts that the [`Outcome`] of a process matches a pattern.
+///
+/// Accepts the same values as [`assert_success!`](crate::assert_success), and prints the same
+/// details on failure.
+///
+/// ```rust
+/// use proc_result::{CrashKind, Outcome, assert_outcome};
+/// use proc_result::unix::WaitStatus;
+///
+/// assert_outcome!(WaitStatus::from_raw(9), Outcome::Killed);
+/// assert_outcome!(
+///     WaitStatus::from_raw(0x8B),
+///     Outcome::Crashed { kind: CrashKind::MemoryAccess, .. },
+/// );
+/// ```
+///
+/// [`Outcome`]: crate::Outcome
+#[macro_export]
+macro_rules! assert_outcome {
+    ($subject:expr, $outcome:pat $(,)?) => {
+        $crate::assert::check(
+            &$subject,
+            |result| ::core::matches!(result.outcome(), $outcome),
+            ::core::format_args!("outcome {}", ::core::stringify!($outcome)),
+            ::core::option::Option::None,
+        )
+    };
+    ($subject:expr, $outcome:pat, $($arg:tt)+) => {
+        $crate::assert::check(
+            &$subject,
+            |result| ::core::matches!(result.outcome(), $outcome),
+            ::core::format_args!("outcome {}", ::core::stringify!($outcome)),
+            ::core::option::Option::Some(::core::format_args!($($arg)+)),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::unix::WaitStatus;
+    use crate::{Outcome, ProcResult};
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let payload = std::panic::catch_unwind(f).unwrap_err();
+        payload.downcast::<String>().map(|s| *s).unwrap()
+    }
+
+    #[test]
+    fn test_passing() {
+        assert_success!(ProcResult::Unix(WaitStatus::from_raw(0)));
+        assert_success!(&WaitStatus::from_raw(0));
+        assert_exit_code!(WaitStatus::from_raw(0x0300), 3);
+        assert_signaled!(WaitStatus::from_raw(0x8F));
+        assert_signaled!(WaitStatus::from_raw(15), crate::unix::Signal::TERMINATION);
+        assert_outcome!(WaitStatus::from_raw(0x0100), Outcome::Failed { code: 1 });
+    }
+
+    #[test]
+    fn test_failure_message() {
+        assert_eq!(
+            panic_message(|| assert_success!(WaitStatus::from_raw(9), "step {}", 2)),
+            "assertion failed: expected success: step 2\n  \
+             result: killed by signal SIGKILL (9)\n     \
+             raw: Unix(WaitStatus(9))"
+        );
+        assert_eq!(
+            panic_message(|| assert_signaled!(WaitStatus::from_raw(0), crate::unix::Signal::KILL)),
+            "assertion failed: expected termination by signal SIGKILL\n  \
+             result: exited with code 0\n     \
+             raw: Unix(WaitStatus(0))"
+        );
+        assert_eq!(
+            panic_message(|| assert_outcome!(WaitStatus::from_raw(0), Outcome::Killed)),
+            "assertion failed: expected outcome Outcome::Killed\n  \
+             result: exited with code 0\n     \
+             raw: Unix(WaitStatus(0))"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_output() {
+        let output = std::process::Command::new("sh")
+            .args(["-c", "echo oops >&2; exit 1"])
+            .output()
+            .unwrap();
+        assert_exit_code!(output, 1);
+        assert_eq!(
+            panic_message(|| assert_success!(output)),
+            "assertion failed: expected success\n  \
+             result: exited with code 1\n     \
+             raw: Unix(WaitStatus(256))\n  \
+             stderr:\n    oops"
+        );
+    }
+}
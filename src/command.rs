@@ -0,0 +1,274 @@
+//! Extensions to [`std::process`] that return [`ProcResult`]-based errors.
+//!
+//! [`CommandExt`] and [`ChildExt`] replace the usual `status()?` followed by a check of the exit
+//! status, with an [`Error`] that tells spawn failures apart from unsuccessful results and
+//! names the command that failed:
+//!
+//! ```rust
+//! # #[cfg(unix)]
+//! # {
+//! use std::process::Command;
+//! use proc_result::command::{CommandExt, Error};
+//!
+//! let error = Command::new("false").status_result().unwrap_err();
+//! assert!(matches!(error, Error::Failed(_)));
+//! assert_eq!(error.to_string(), "command `false` exited with code 1");
+//! # }
+//! ```
+
+use std::fmt::Display;
+use std::io;
+use std::process::{Child, Command, Output};
+
+use crate::ProcResult;
+use crate::error::{CommandLine, ProcessError};
+
+/// An error returned by [`CommandExt`] and [`ChildExt`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// The process could not be started.
+    Spawn {
+        /// The command that could not be started.
+        command: CommandLine,
+
+        /// The error returned when spawning the process.
+        source: io::Error,
+    },
+
+    /// Waiting for the process, or reading its output, failed.
+    Io {
+        /// The command that was running.
+        command: CommandLine,
+
+        /// The error returned when waiting for the process.
+        source: io::Error,
+    },
+
+    /// The process ran, but did not succeed.
+    Failed(Box<ProcessError>),
+}
+
+impl Error {
+    /// Returns the command that failed.
+    #[must_use]
+    pub fn command(&self) -> &CommandLine {
+        match self {
+            Self::Spawn { command, .. } | Self::Io { command, .. } => command,
+            Self::Failed(error) => error.command(),
+        }
+    }
+
+    /// Returns the result of the process, if it ran or could not be started.
+    ///
+    /// Spawn failures are returned as [`ProcResult::NotStarted`].
+    #[must_use]
+    pub fn result(&self) -> Option<ProcResult> {
+        match self {
+            Self::Spawn { source, .. } => Some(ProcResult::NotStarted(source.into())),
+            Self::Io { .. } => None,
+            Self::Failed(error) => Some(error.result()),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spawn { command, source } => {
+                write!(f, "command `{command}` failed to start: {source}")
+            }
+            Self::Io { command, source } => {
+                write!(f, "command `{command}` could not be waited on: {source}")
+            }
+            Self::Failed(error) => error.fmt(f),
+        }
+    }
+}
+
+/// Like [`ProcessError`], the message already includes the underlying error or result, so
+/// `source()` returns `None` rather than repeating it in error-chain reports.
+impl std::error::Error for Error {}
+
+impl From<ProcessError> for Error {
+    fn from(error: ProcessError) -> Self {
+        Self::Failed(Box::new(error))
+    }
+}
+
+/// Extensions to [`Command`] that run it and check its result.
+pub trait CommandExt {
+    /// Runs the command like [`Command::status`], returning an error unless it succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spawn`] if the command could not be started, [`Error::Io`] if waiting
+    /// for it failed, and [`Error::Failed`] if it did not succeed.
+    fn status_result(&mut self) -> Result<ProcResult, Error>;
+
+    /// Runs the command like [`Command::output`], returning an error unless it succeeds.
+    ///
+    /// On failure, the error includes excerpts of the captured output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spawn`] if the command could not be run, as [`Command::output`] does not
+    /// report a status then, and [`Error::Failed`] if it did not succeed.
+    fn output_result(&mut self) -> Result<Output, Error>;
+}
+
+impl CommandExt for Command {
+    fn status_result(&mut self) -> Result<ProcResult, Error> {
+        let mut child = self.spawn().map_err(|source| Error::Spawn {
+            command: CommandLine::from(&*self),
+            source,
+        })?;
+        child.wait_result(&*self)
+    }
+
+    fn output_result(&mut self) -> Result<Output, Error> {
+        let output = self.output().map_err(|source| Error::Spawn {
+            command: CommandLine::from(&*self),
+            source,
+        })?;
+        check_output(CommandLine::from(&*self), output)
+    }
+}
+
+/// Extensions to [`Child`] that wait for it and check its result.
+///
+/// A [`Child`] does not know the command that started it, so it is passed in to be included in
+/// errors.
+pub trait ChildExt {
+    /// Waits for the process like [`Child::wait`], returning an error unless it succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if waiting for the process failed, and [`Error::Failed`] if it did
+    /// not succeed.
+    fn wait_result(&mut self, command: impl Into<CommandLine>) -> Result<ProcResult, Error>;
+
+    /// Waits for the process like [`Child::wait_with_output`], returning an error unless it
+    /// succeeds.
+    ///
+    /// On failure, the error includes excerpts of the captured output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if waiting for the process failed, and [`Error::Failed`] if it did
+    /// not succeed.
+    fn wait_with_output_result(self, command: impl Into<CommandLine>) -> Result<Output, Error>;
+}
+
+impl ChildExt for Child {
+    fn wait_result(&mut self, command: impl Into<CommandLine>) -> Result<ProcResult, Error> {
+        match self.wait() {
+            Ok(status) => check(command.into(), status.into()),
+            Err(source) => Err(Error::Io {
+                command: command.into(),
+                source,
+            }),
+        }
+    }
+
+    fn wait_with_output_result(self, command: impl Into<CommandLine>) -> Result<Output, Error> {
+        match self.wait_with_output() {
+            Ok(output) => check_output(command.into(), output),
+            Err(source) => Err(Error::Io {
+                command: command.into(),
+                source,
+            }),
+        }
+    }
+}
+
+fn check(command: CommandLine, result: ProcResult) -> Result<ProcResult, Error> {
+    if result.is_success() {
+        Ok(result)
+    } else {
+        Err(ProcessError::new(command, result).into())
+    }
+}
+
+fn check_output(command: CommandLine, output: Output) -> Result<Output, Error> {
+    let result = ProcResult::from(output.status);
+    if result.is_success() {
+        return Ok(output);
+    }
+    Err(ProcessError::new(command, result)
+        .with_stdout(&output.stdout)
+        .with_stderr(&output.stderr)
+        .into())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::process::Stdio;
+
+    use super::*;
+
+    #[test]
+    fn test_status_result() {
+        let result = Command::new("true").status_result().unwrap();
+        assert!(result.is_success());
+    }
+
+    #[test]
+    fn test_status_result_failed() {
+        let error = Command::new("sh")
+            .args(["-c", "exit 3"])
+            .status_result()
+            .unwrap_err();
+        let Error::Failed(error) = error else {
+            panic!("expected Error::Failed, got {error:?}");
+        };
+        assert_eq!(error.result().to_string(), "exited with code 3");
+        assert_eq!(error.command().to_string(), "sh -c 'exit 3'");
+    }
+
+    #[test]
+    fn test_status_result_spawn() {
+        let error = Command::new("proc-result-does-not-exist")
+            .status_result()
+            .unwrap_err();
+        assert!(matches!(error, Error::Spawn { .. }));
+        assert!(matches!(error.result(), Some(ProcResult::NotStarted(_))));
+        assert!(
+            error
+                .to_string()
+                .starts_with("command `proc-result-does-not-exist` failed to start: ")
+        );
+        assert!(std::error::Error::source(&error).is_none());
+    }
+
+    #[test]
+    fn test_output_result() {
+        let output = Command::new("sh")
+            .args(["-c", "echo out"])
+            .output_result()
+            .unwrap();
+        assert_eq!(output.stdout, b"out\n");
+
+        let error = Command::new("sh")
+            .args(["-c", "echo oops >&2; exit 1"])
+            .output_result()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "command `sh -c 'echo oops >&2; exit 1'` exited with code 1\nstderr:\n    oops"
+        );
+        let error = Command::new("proc-result-does-not-exist")
+            .output_result()
+            .unwrap_err();
+        assert!(matches!(error, Error::Spawn { .. }));
+    }
+
+    #[test]
+    fn test_wait_result() {
+        let mut command = Command::new("sh");
+        command.args(["-c", "exit 2"]).stdout(Stdio::null());
+        let mut child = command.spawn().unwrap();
+        let error = child.wait_result(&command).unwrap_err();
+        assert_eq!(error.command().to_string(), "sh -c 'exit 2'");
+    }
+}
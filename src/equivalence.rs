@@ -0,0 +1,214 @@
+//! Semantic equivalences between Windows exit codes and Unix signals.
+//!
+//! Windows has no signals; the conditions that terminate a Unix process with a signal instead
+//! terminate a Windows process with an `NTSTATUS` exception code. This module pairs the two, so
+//! that results recorded on different platforms can be compared.
+//!
+//! Each pairing carries a [`Confidence`], as few are exact; for example, both
+//! [`ExitCode::ACCESS_VIOLATION`] and [`ExitCode::STACK_OVERFLOW`] are reported as `SIGSEGV` on
+//! Unix, but only the former is raised by exactly the same condition.
+//!
+//! ```rust
+//! use proc_result::equivalence::{self, Confidence};
+//! use proc_result::unix::Signal;
+//! use proc_result::windows::ExitCode;
+//!
+//! let equivalence = equivalence::for_windows(ExitCode::TERMINATED_BY_CTRL_C).unwrap();
+//! assert_eq!(equivalence.signal(), Signal::INTERRUPT);
+//! assert_eq!(equivalence.confidence(), Confidence::High);
+//! ```
+
+use crate::unix::{Signal, WaitState, WaitStatus};
+use crate::windows::ExitCode;
+
+/// How closely a Windows exit code and a Unix signal describe the same event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// The two share a cause only by convention, and often mean something else entirely.
+    Low,
+
+    /// The two share a cause, but one side also covers other causes.
+    Medium,
+
+    /// The two are raised by the same underlying condition.
+    High,
+}
+
+/// A pairing of a Windows exit code and a Unix signal that describe the same event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Equivalence {
+    windows: ExitCode,
+    signal: Signal,
+    confidence: Confidence,
+}
+
+impl Equivalence {
+    const fn new(windows: ExitCode, signal: Signal, confidence: Confidence) -> Self {
+        Self {
+            windows,
+            signal,
+            confidence,
+        }
+    }
+
+    /// Returns the Windows side of the pairing.
+    #[must_use]
+    pub const fn windows(&self) -> ExitCode {
+        self.windows
+    }
+
+    /// Returns the Unix side of the pairing.
+    #[must_use]
+    pub const fn signal(&self) -> Signal {
+        self.signal
+    }
+
+    /// Returns the Unix side of the pairing as the wait status of a process killed by it.
+    #[must_use]
+    pub const fn unix_status(&self) -> WaitStatus {
+        WaitStatus::from_raw(
+            WaitState::Signaled {
+                signal: self.signal,
+                core_dump: false,
+            }
+            .to_raw(),
+        )
+    }
+
+    /// Returns how closely the two sides describe the same event.
+    #[must_use]
+    pub const fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+}
+
+/// All known equivalences, ordered so that the preferred Windows code for a signal comes first.
+///
+/// [`CHILD_KILL`] is intentionally not included.
+pub const EQUIVALENCES: &[Equivalence] = &[
+    Equivalence::new(
+        ExitCode::TERMINATED_BY_CTRL_C,
+        Signal::INTERRUPT,
+        Confidence::High,
+    ),
+    Equivalence::new(
+        ExitCode::ACCESS_VIOLATION,
+        Signal::SEGMENTATION_VIOLATION,
+        Confidence::High,
+    ),
+    Equivalence::new(
+        ExitCode::STACK_OVERFLOW,
+        Signal::SEGMENTATION_VIOLATION,
+        Confidence::Medium,
+    ),
+    Equivalence::new(
+        ExitCode::ILLEGAL_INSTRUCTION,
+        Signal::ILLEGAL_INSTRUCTION,
+        Confidence::High,
+    ),
+    Equivalence::new(
+        ExitCode::PRIVILEGED_INSTRUCTION,
+        Signal::ILLEGAL_INSTRUCTION,
+        Confidence::Medium,
+    ),
+    Equivalence::new(
+        ExitCode::INTEGER_DIVIDE_BY_ZERO,
+        Signal::FLOATING_POINT_EXCEPTION,
+        Confidence::High,
+    ),
+    Equivalence::new(
+        ExitCode::INTEGER_OVERFLOW,
+        Signal::FLOATING_POINT_EXCEPTION,
+        Confidence::Medium,
+    ),
+    Equivalence::new(
+        ExitCode::FLOAT_DIVIDE_BY_ZERO,
+        Signal::FLOATING_POINT_EXCEPTION,
+        Confidence::Medium,
+    ),
+    Equivalence::new(
+        ExitCode::IN_PAGE_ERROR,
+        Signal::BUS_ERROR,
+        Confidence::Medium,
+    ),
+    Equivalence::new(
+        ExitCode::DATATYPE_MISALIGNMENT,
+        Signal::BUS_ERROR,
+        Confidence::Medium,
+    ),
+    Equivalence::new(ExitCode::BREAKPOINT, Signal::TRAP, Confidence::High),
+    Equivalence::new(
+        ExitCode::STACK_BUFFER_OVERRUN,
+        Signal::ABORT,
+        Confidence::Medium,
+    ),
+];
+
+/// What [`std::process::Child::kill`] produces on each platform.
+///
+/// On Unix, the child is sent `SIGKILL`. On Windows, the child is terminated with
+/// `TerminateProcess`, which the standard library calls with an exit code of `1`; that is
+/// indistinguishable from a process that exited with [`ExitCode::GENERAL_ERROR`] on its own, so
+/// this pairing has [`Confidence::Low`] and is not returned by [`for_windows`] or [`for_signal`].
+pub const CHILD_KILL: Equivalence =
+    Equivalence::new(ExitCode::GENERAL_ERROR, Signal::KILL, Confidence::Low);
+
+/// Returns the Unix signal equivalent to a Windows exit code, if any.
+#[must_use]
+pub fn for_windows(code: ExitCode) -> Option<Equivalence> {
+    EQUIVALENCES.iter().find(|e| e.windows == code).copied()
+}
+
+/// Returns the Windows exit code equivalent to a Unix signal, if any.
+///
+/// If several exit codes are equivalent, the one with the highest [`Confidence`] is returned.
+#[must_use]
+pub fn for_signal(signal: Signal) -> Option<Equivalence> {
+    EQUIVALENCES
+        .iter()
+        .filter(|e| e.signal == signal)
+        .fold(None, |best: Option<&Equivalence>, e| match best {
+            Some(best) if best.confidence >= e.confidence => Some(best),
+            _ => Some(e),
+        })
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_windows() {
+        let e = for_windows(ExitCode::STACK_OVERFLOW).unwrap();
+        assert_eq!(e.signal(), Signal::SEGMENTATION_VIOLATION);
+        assert_eq!(e.confidence(), Confidence::Medium);
+
+        assert_eq!(for_windows(ExitCode::GENERAL_ERROR), None);
+    }
+
+    #[test]
+    fn test_for_signal() {
+        let e = for_signal(Signal::SEGMENTATION_VIOLATION).unwrap();
+        assert_eq!(e.windows(), ExitCode::ACCESS_VIOLATION);
+        assert_eq!(e.confidence(), Confidence::High);
+
+        let e = for_signal(Signal::BUS_ERROR).unwrap();
+        assert_eq!(e.windows(), ExitCode::IN_PAGE_ERROR);
+
+        assert_eq!(for_signal(Signal::KILL), None);
+    }
+
+    #[test]
+    fn test_child_kill() {
+        assert_eq!(CHILD_KILL.windows(), ExitCode::GENERAL_ERROR);
+        assert_eq!(CHILD_KILL.unix_status().signal(), Some(Signal::KILL));
+        assert_eq!(CHILD_KILL.confidence(), Confidence::Low);
+    }
+
+    #[test]
+    fn test_unix_status() {
+        let e = for_windows(ExitCode::TERMINATED_BY_CTRL_C).unwrap();
+        assert_eq!(e.unix_status().to_raw(), 2);
+    }
+}
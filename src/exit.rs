@@ -0,0 +1,216 @@
+//! Ends the current process the way another process ended.
+
+use crate::unix::{Signal, WaitState};
+use crate::windows::ExitCode;
+use crate::{ProcResult, SpawnErrorKind, equivalence};
+
+/// Exit code of a process that could not be started because it was not found, as in a shell.
+const NOT_FOUND: i32 = 127;
+
+/// Exit code of a process that was found, but could not be started, as in a shell.
+const CANNOT_EXECUTE: i32 = 126;
+
+/// Exit code of a process that timed out, as in `timeout(1)`.
+const TIMED_OUT: i32 = 124;
+
+/// How the current process ends, on this platform.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Exit {
+    /// Exits with a code.
+    Code(i32),
+
+    /// Raises a signal with its default action, or exits with `128 + signal` if that returns.
+    #[cfg(unix)]
+    Signal(Signal),
+}
+
+impl Exit {
+    fn for_result(result: &ProcResult) -> Self {
+        match result {
+            ProcResult::Unix(status) => match status.state() {
+                WaitState::Exited { exit_code } => Self::Code(i32::from(exit_code.to_raw())),
+                WaitState::Signaled { signal, .. } => Self::for_signal(signal),
+                // A shell reports a stopped job as `128 + signal`.
+                WaitState::Stopped { signal } => Self::Code(128 + i32::from(signal.to_raw())),
+                WaitState::Continued | WaitState::Unsupported(_) => Self::Code(1),
+            },
+            ProcResult::Windows(code) => Self::for_windows(*code),
+            ProcResult::NotStarted(error) if error.kind() == SpawnErrorKind::NotFound => {
+                Self::Code(NOT_FOUND)
+            }
+            ProcResult::NotStarted(_) => Self::Code(CANNOT_EXECUTE),
+            ProcResult::TimedOut(_) => Self::Code(TIMED_OUT),
+        }
+    }
+
+    #[cfg(unix)]
+    const fn for_signal(signal: Signal) -> Self {
+        Self::Signal(signal)
+    }
+
+    #[cfg(windows)]
+    fn for_signal(signal: Signal) -> Self {
+        match equivalence::for_signal(signal) {
+            Some(equivalence) => Self::for_windows(equivalence.windows()),
+            None => Self::Code(128 + i32::from(signal.to_raw())),
+        }
+    }
+
+    #[cfg(unix)]
+    fn for_windows(code: ExitCode) -> Self {
+        if let Some(equivalence) = equivalence::for_windows(code) {
+            return Self::Signal(equivalence.signal());
+        }
+        // A code that does not fit in 8 bits would be truncated, possibly to `0`.
+        Self::Code(u8::try_from(code.to_raw()).map_or(1, i32::from))
+    }
+
+    #[cfg(windows)]
+    const fn for_windows(code: ExitCode) -> Self {
+        Self::Code(code.to_raw().cast_signed())
+    }
+}
+
+/// See [`ProcResult::exit_like`].
+pub(crate) fn exit_like(result: &ProcResult) -> ! {
+    match Exit::for_result(result) {
+        Exit::Code(code) => std::process::exit(code),
+        #[cfg(unix)]
+        Exit::Signal(signal) => {
+            raise(signal);
+            std::process::exit(128 + i32::from(signal.to_raw()))
+        }
+    }
+}
+
+/// Raises `signal` in the current process with its default action.
+///
+/// The signal is reset to its default action with `sigaction`, and unblocked in the calling
+/// thread, as a handler or a blocked signal mask (i.e. inherited from the parent) would otherwise
+/// keep the process alive. Returns if the signal does not terminate the process, i.e. because its
+/// default action is to ignore it.
+#[cfg(unix)]
+fn raise(sig: Signal) {
+    use core::{mem, ptr};
+    use std::io::Write;
+
+    let signum = libc::c_int::from(sig.to_raw());
+
+    // Unlike `std::process::exit`, a signal does not flush standard output.
+    let _ = std::io::stdout().flush();
+
+    // SAFETY: Resetting a signal to its default action, unblocking it, and raising it, are always
+    // sound, and both structures are initialized by `sigemptyset` before they are read. The
+    // results are irrelevant, as the process exits if it survives.
+    unsafe {
+        let mut action: libc::sigaction = mem::zeroed();
+        action.sa_sigaction = libc::SIG_DFL;
+        libc::sigemptyset(&raw mut action.sa_mask);
+        libc::sigaction(signum, &raw const action, ptr::null_mut());
+
+        let mut set: libc::sigset_t = mem::zeroed();
+        libc::sigemptyset(&raw mut set);
+        libc::sigaddset(&raw mut set, signum);
+        libc::pthread_sigmask(libc::SIG_UNBLOCK, &raw const set, ptr::null_mut());
+
+        libc::raise(signum);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::SpawnError;
+    use crate::unix::WaitStatus;
+
+    fn unix(raw: i32) -> Exit {
+        Exit::for_result(&ProcResult::Unix(WaitStatus::from_raw(raw)))
+    }
+
+    fn windows(raw: u32) -> Exit {
+        Exit::for_result(&ProcResult::Windows(ExitCode::from_raw(raw)))
+    }
+
+    #[test]
+    fn test_exited() {
+        assert_eq!(unix(0x0000), Exit::Code(0));
+        assert_eq!(unix(0x0100), Exit::Code(1));
+        assert_eq!(unix(0xFF00), Exit::Code(255));
+        assert_eq!(unix(0x147F), Exit::Code(148));
+        assert_eq!(unix(0xFFFF), Exit::Code(1));
+    }
+
+    #[test]
+    fn test_other() {
+        let not_started =
+            |kind| Exit::for_result(&ProcResult::NotStarted(SpawnError::new(kind, None)));
+        assert_eq!(not_started(SpawnErrorKind::NotFound), Exit::Code(127));
+        assert_eq!(
+            not_started(SpawnErrorKind::PermissionDenied),
+            Exit::Code(126)
+        );
+        assert_eq!(
+            Exit::for_result(&ProcResult::TimedOut(Some(Duration::from_secs(1)))),
+            Exit::Code(124)
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_unix() {
+        assert_eq!(unix(9), Exit::Signal(Signal::KILL));
+        assert_eq!(unix(0x8B), Exit::Signal(Signal::SEGMENTATION_VIOLATION));
+        assert_eq!(windows(0), Exit::Code(0));
+        assert_eq!(windows(2), Exit::Code(2));
+        assert_eq!(windows(256), Exit::Code(1));
+        assert_eq!(windows(0xC000_013A), Exit::Signal(Signal::INTERRUPT));
+        assert_eq!(
+            windows(0xC000_0005),
+            Exit::Signal(Signal::SEGMENTATION_VIOLATION)
+        );
+        assert_eq!(windows(0xC000_0135), Exit::Code(1));
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn test_windows() {
+        assert_eq!(unix(9), Exit::Code(137));
+        assert_eq!(unix(0x8B), Exit::Code(0xC000_0005_u32.cast_signed()));
+        assert_eq!(windows(0), Exit::Code(0));
+        assert_eq!(
+            windows(0xC000_0135),
+            Exit::Code(0xC000_0135_u32.cast_signed())
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_exit_like_raises() {
+        use std::process::Command;
+
+        /// Set in the child process, which runs only this test.
+        const CHILD: &str = "PROC_RESULT_TEST_EXIT_LIKE";
+
+        if std::env::var_os(CHILD).is_some() {
+            // SAFETY: Ignoring and blocking a signal in the test thread is sound.
+            unsafe {
+                let mut set: libc::sigset_t = core::mem::zeroed();
+                libc::sigemptyset(&raw mut set);
+                libc::sigaddset(&raw mut set, libc::SIGTERM);
+                libc::pthread_sigmask(libc::SIG_BLOCK, &raw const set, core::ptr::null_mut());
+                libc::signal(libc::SIGTERM, libc::SIG_IGN);
+            }
+            exit_like(&ProcResult::Unix(WaitStatus::from_raw(15)));
+        }
+
+        let status = Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "exit::tests::test_exit_like_raises"])
+            .env(CHILD, "1")
+            .output()
+            .unwrap()
+            .status;
+        assert_eq!(WaitStatus::from(status).signal(), Some(Signal::TERMINATION));
+    }
+}
@@ -0,0 +1,423 @@
+//! Results that count as success, for programs that use non-zero exit codes for normal outcomes.
+//!
+//! [`ProcResult::ok`] only accepts exit code `0`, but `grep` exits with `1` when nothing
+//! matched, and `git bisect run` treats `125` as "skip". An [`ExpectedOutcome`] describes which
+//! results are acceptable, and [`ProcResult::ok_if`] checks a result against it:
+//!
+//! ```rust
+//! use proc_result::ProcResult;
+//! use proc_result::expected::ExpectedOutcome;
+//! use proc_result::unix::WaitStatus;
+//!
+//! // `grep` exits with 0 if a line matched, 1 if none did, and 2 on errors.
+//! let expected = ExpectedOutcome::success().code(1);
+//!
+//! let result = ProcResult::Unix(WaitStatus::from_raw(0x0100));
+//! assert!(result.ok_if(&expected).is_ok());
+//!
+//! let result = ProcResult::Unix(WaitStatus::from_raw(0x0200));
+//! assert_eq!(
+//!     result.ok_if(&expected).unwrap_err().to_string(),
+//!     "exited with code 2, expected exit code 0 or exit code 1",
+//! );
+//! ```
+//!
+//! Outcomes can also be written as specs for configuration files, which use the crate's exit code
+//! and signal names and round-trip through [`Display`] and [`FromStr`]:
+//!
+//! ```rust
+//! use proc_result::expected::ExpectedOutcome;
+//!
+//! let expected: ExpectedOutcome = "0,2-5,EX_TEMPFAIL,SIGTERM,!SIGKILL,win:0xC000013A".parse()?;
+//! assert_eq!(expected.to_string(), "0,2-5,unix:75,win:0xC000013A,SIGTERM,!SIGKILL");
+//! # Ok::<(), proc_result::expected::ParseSpecError>(())
+//! ```
+//!
+//! A spec is a comma-separated list of items, each of which is:
+//!
+//! - an exit code on either platform, in decimal or `0x` hexadecimal, i.e. `1` or `0x10`;
+//! - a range of exit codes, i.e. `2-5`;
+//! - a symbolic name, i.e. `EX_TEMPFAIL`, `STATUS_CONTROL_C_EXIT`, `SIGTERM` or `SIG64`.
+//!
+//! Codes can be limited to a platform with a `unix:` or `win:` prefix, and items prefixed with
+//! `!` are rejected rather than accepted.
+//!
+//! [`FromStr`]: core::str::FromStr
+//! [`ProcResult::ok`]: crate::ProcResult::ok
+//! [`ProcResult::ok_if`]: crate::ProcResult::ok_if
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::ops::RangeInclusive;
+
+use crate::ProcResult;
+use crate::unix::{Signal, WaitState};
+use crate::windows::{ExitCode, ExitCodeFormat};
+
+mod spec;
+pub use spec::{ParseSpecError, SpecErrorKind};
+
+/// A set of results, matched by [`ExpectedOutcome`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Pattern {
+    /// An exit code in the inclusive range `min..=max`, on either platform.
+    Code {
+        /// The lowest exit code in the range.
+        min: u32,
+
+        /// The highest exit code in the range.
+        max: u32,
+    },
+
+    /// A Unix exit code in the inclusive range `min..=max`.
+    UnixCode {
+        /// The lowest exit code in the range.
+        min: u8,
+
+        /// The highest exit code in the range.
+        max: u8,
+    },
+
+    /// A Windows exit code in the inclusive range `min..=max`.
+    WindowsCode {
+        /// The lowest exit code in the range.
+        min: u32,
+
+        /// The highest exit code in the range.
+        max: u32,
+    },
+
+    /// A termination by a Unix signal.
+    Signal(Signal),
+}
+
+impl Pattern {
+    /// Returns `true` if the result is in the set.
+    ///
+    /// Results that do not represent a terminated process never match.
+    #[must_use]
+    pub fn matches(&self, result: &ProcResult) -> bool {
+        match (self, result) {
+            (Self::Code { min, max }, ProcResult::Unix(status)) => status
+                .exit_code()
+                .is_some_and(|code| (*min..=*max).contains(&u32::from(code.to_raw()))),
+            (Self::UnixCode { min, max }, ProcResult::Unix(status)) => status
+                .exit_code()
+                .is_some_and(|code| (*min..=*max).contains(&code.to_raw())),
+            (Self::Signal(signal), ProcResult::Unix(status)) => matches!(
+                status.state(),
+                WaitState::Signaled { signal: s, .. } if s == *signal
+            ),
+            (
+                Self::Code { min, max } | Self::WindowsCode { min, max },
+                ProcResult::Windows(code),
+            ) => (*min..=*max).contains(&code.to_raw()),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Pattern {
+    /// Describes the set, i.e. `exit code 1` or `Windows exit code 0xC000013A`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Code { min, max } if min == max => write!(f, "exit code {min}"),
+            Self::Code { min, max } => write!(f, "exit code {min}-{max}"),
+            Self::UnixCode { min, max } if min == max => write!(f, "Unix exit code {min}"),
+            Self::UnixCode { min, max } => write!(f, "Unix exit code {min}-{max}"),
+            Self::WindowsCode { min, max } => {
+                let hex = |code| ExitCode::from_raw(code).format(ExitCodeFormat::Hex);
+                if min == max {
+                    write!(f, "Windows exit code {}", hex(min))
+                } else {
+                    write!(f, "Windows exit code {}-{}", hex(min), hex(max))
+                }
+            }
+            Self::Signal(signal) => match signal.name() {
+                Some(name) => write!(f, "signal {name}"),
+                None => write!(f, "signal {signal}"),
+            },
+        }
+    }
+}
+
+/// Describes which results of a process count as success.
+///
+/// A result is expected if it matches an accepted [`Pattern`] and no rejected one; rejections
+/// take precedence, so broad ranges can have exceptions:
+///
+/// ```rust
+/// use proc_result::ProcResult;
+/// use proc_result::expected::ExpectedOutcome;
+/// use proc_result::unix::{Signal, WaitStatus};
+///
+/// // `git bisect run`: 0 is good, 1-127 is bad, except 125, which means skip.
+/// let bisectable = ExpectedOutcome::new().codes(0..=127).reject_code(125);
+/// assert!(bisectable.matches(&ProcResult::Unix(WaitStatus::from_raw(0x0100))));
+/// assert!(!bisectable.matches(&ProcResult::Unix(WaitStatus::from_raw(0x7D00))));
+///
+/// // A server that is expected to be stopped with `SIGTERM`.
+/// let server = ExpectedOutcome::success().signal(Signal::TERMINATION);
+/// assert!(server.matches(&ProcResult::Unix(WaitStatus::from_raw(15))));
+/// ```
+///
+/// With the `serde` feature, outcomes are serialized as specs, i.e. `"0,1,!SIGKILL"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExpectedOutcome {
+    accepted: Vec<Pattern>,
+    rejected: Vec<Pattern>,
+}
+
+impl ExpectedOutcome {
+    /// Creates an `ExpectedOutcome` that accepts nothing.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            accepted: Vec::new(),
+            rejected: Vec::new(),
+        }
+    }
+
+    /// Creates an `ExpectedOutcome` that accepts exit code `0`, like [`ProcResult::ok`].
+    #[must_use]
+    pub fn success() -> Self {
+        Self::new().code(0)
+    }
+
+    /// Accepts results in the set.
+    #[must_use]
+    pub fn accept(mut self, pattern: Pattern) -> Self {
+        self.accepted.push(pattern);
+        self
+    }
+
+    /// Rejects results in the set, even if they are accepted by another pattern.
+    #[must_use]
+    pub fn reject(mut self, pattern: Pattern) -> Self {
+        self.rejected.push(pattern);
+        self
+    }
+
+    /// Accepts an exit code, on either platform.
+    #[must_use]
+    pub fn code(self, code: u32) -> Self {
+        self.accept(Pattern::Code {
+            min: code,
+            max: code,
+        })
+    }
+
+    /// Accepts a range of exit codes, on either platform.
+    #[must_use]
+    pub fn codes(self, codes: RangeInclusive<u32>) -> Self {
+        self.accept(Pattern::Code {
+            min: *codes.start(),
+            max: *codes.end(),
+        })
+    }
+
+    /// Accepts a Unix exit code.
+    #[must_use]
+    pub fn unix_code(self, code: u8) -> Self {
+        self.accept(Pattern::UnixCode {
+            min: code,
+            max: code,
+        })
+    }
+
+    /// Accepts a Windows exit code.
+    #[must_use]
+    pub fn windows_code(self, code: u32) -> Self {
+        self.accept(Pattern::WindowsCode {
+            min: code,
+            max: code,
+        })
+    }
+
+    /// Accepts a termination by a Unix signal.
+    #[must_use]
+    pub fn signal(self, signal: Signal) -> Self {
+        self.accept(Pattern::Signal(signal))
+    }
+
+    /// Rejects an exit code, on either platform.
+    #[must_use]
+    pub fn reject_code(self, code: u32) -> Self {
+        self.reject(Pattern::Code {
+            min: code,
+            max: code,
+        })
+    }
+
+    /// Rejects a termination by a Unix signal.
+    #[must_use]
+    pub fn reject_signal(self, signal: Signal) -> Self {
+        self.reject(Pattern::Signal(signal))
+    }
+
+    /// Returns the accepted patterns, in the order they were added.
+    #[must_use]
+    pub fn accepted(&self) -> &[Pattern] {
+        &self.accepted
+    }
+
+    /// Returns the rejected patterns, in the order they were added.
+    #[must_use]
+    pub fn rejected(&self) -> &[Pattern] {
+        &self.rejected
+    }
+
+    /// Returns `true` if the result is expected.
+    #[must_use]
+    pub fn matches(&self, result: &ProcResult) -> bool {
+        self.accepted.iter().any(|p| p.matches(result))
+            && !self.rejected.iter().any(|p| p.matches(result))
+    }
+}
+
+impl Default for ExpectedOutcome {
+    /// Returns [`ExpectedOutcome::success`].
+    fn default() -> Self {
+        Self::success()
+    }
+}
+
+/// An error returned by [`ProcResult::ok_if`] when a result is not expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnexpectedOutcome {
+    result: ProcResult,
+    expected: ExpectedOutcome,
+}
+
+impl UnexpectedOutcome {
+    pub(crate) fn new(result: ProcResult, expected: ExpectedOutcome) -> Self {
+        Self { result, expected }
+    }
+
+    /// Returns the result that was not expected.
+    #[must_use]
+    pub fn result(&self) -> ProcResult {
+        self.result
+    }
+
+    /// Returns the outcome that was expected.
+    #[must_use]
+    pub fn expected(&self) -> &ExpectedOutcome {
+        &self.expected
+    }
+}
+
+impl Display for UnexpectedOutcome {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fn list(f: &mut core::fmt::Formatter<'_>, patterns: &[Pattern]) -> core::fmt::Result {
+            for (i, pattern) in patterns.iter().enumerate() {
+                match i {
+                    0 => {}
+                    _ if i == patterns.len() - 1 => f.write_str(" or ")?,
+                    _ => f.write_str(", ")?,
+                }
+                write!(f, "{pattern}")?;
+            }
+            Ok(())
+        }
+
+        write!(f, "{}, expected ", self.result)?;
+        if self.expected.accepted.is_empty() {
+            return f.write_str("nothing");
+        }
+        list(f, &self.expected.accepted)?;
+        if !self.expected.rejected.is_empty() {
+            f.write_str(" but not ")?;
+            list(f, &self.expected.rejected)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result is part of the message, so `source()` returns `None`; use
+/// [`UnexpectedOutcome::result`] instead.
+impl core::error::Error for UnexpectedOutcome {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unix::WaitStatus;
+
+    fn unix(raw: i32) -> ProcResult {
+        ProcResult::Unix(WaitStatus::from_raw(raw))
+    }
+
+    fn windows(raw: u32) -> ProcResult {
+        ProcResult::Windows(ExitCode::from_raw(raw))
+    }
+
+    #[test]
+    fn test_success() {
+        let expected = ExpectedOutcome::default();
+        assert!(expected.matches(&unix(0)));
+        assert!(expected.matches(&windows(0)));
+        assert!(!expected.matches(&unix(0x0100)));
+        assert!(!expected.matches(&unix(0x0F)));
+        assert!(!expected.matches(&ProcResult::TimedOut(None)));
+    }
+
+    #[test]
+    fn test_codes() {
+        let expected = ExpectedOutcome::new().codes(2..=5).reject_code(4);
+        assert!(expected.matches(&unix(0x0200)));
+        assert!(expected.matches(&windows(5)));
+        assert!(!expected.matches(&unix(0x0400)));
+        assert!(!expected.matches(&unix(0x0600)));
+    }
+
+    #[test]
+    fn test_per_platform() {
+        let expected = ExpectedOutcome::new()
+            .unix_code(3)
+            .windows_code(0xC000_013A);
+        assert!(expected.matches(&unix(0x0300)));
+        assert!(!expected.matches(&windows(3)));
+        assert!(expected.matches(&windows(0xC000_013A)));
+    }
+
+    #[test]
+    fn test_signals() {
+        let expected = ExpectedOutcome::new()
+            .signal(Signal::TERMINATION)
+            .reject_signal(Signal::KILL);
+        assert!(expected.matches(&unix(15)));
+        assert!(expected.matches(&unix(0x8F)));
+        assert!(!expected.matches(&unix(9)));
+        assert!(!expected.matches(&unix(0x0F00)));
+    }
+
+    #[test]
+    fn test_ok_if() {
+        let expected = ExpectedOutcome::success()
+            .signal(Signal::TERMINATION)
+            .windows_code(0xC000_013A)
+            .reject_code(0);
+        assert_eq!(
+            unix(9).ok_if(&expected).unwrap_err().to_string(),
+            "killed by signal SIGKILL (9), expected exit code 0, signal SIGTERM or \
+             Windows exit code 0xC000013A but not exit code 0"
+        );
+        assert_eq!(
+            unix(0)
+                .ok_if(&ExpectedOutcome::new())
+                .unwrap_err()
+                .to_string(),
+            "exited with code 0, expected nothing"
+        );
+        assert!(unix(15).ok_if(&expected).is_ok());
+    }
+
+    #[test]
+    fn test_unexpected_source() {
+        use core::error::Error;
+
+        let error = unix(9).ok_if(&ExpectedOutcome::success()).unwrap_err();
+        assert!(error.source().is_none());
+    }
+}
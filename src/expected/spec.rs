@@ -0,0 +1,451 @@
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::ops::Range;
+use core::str::FromStr;
+
+use super::{ExpectedOutcome, Pattern};
+use crate::unix::{self, Signal};
+use crate::windows;
+
+/// An error which can be returned when parsing an [`ExpectedOutcome`] from a spec.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseSpecError {
+    kind: SpecErrorKind,
+    span: Range<usize>,
+}
+
+impl ParseSpecError {
+    fn new(kind: SpecErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Returns the kind of error.
+    #[must_use]
+    pub fn kind(&self) -> SpecErrorKind {
+        self.kind
+    }
+
+    /// Returns the byte range of the spec that could not be parsed.
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl Display for ParseSpecError {
+    /// Describes the error and where it is, i.e. `unknown name at 4..10`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at {}..{}", self.kind, self.span.start, self.span.end)
+    }
+}
+
+impl core::error::Error for ParseSpecError {}
+
+/// The kind of a [`ParseSpecError`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SpecErrorKind {
+    /// An item between commas is empty.
+    EmptyItem,
+
+    /// A number is not decimal or `0x`-prefixed hexadecimal.
+    InvalidNumber,
+
+    /// A number is too large, i.e. a Unix exit code above `255`.
+    OutOfRange,
+
+    /// The start of a range is greater than its end.
+    InvalidRange,
+
+    /// A name is not a known exit code or signal name.
+    UnknownName,
+
+    /// A prefix is not `unix:` or `win:`.
+    UnknownPrefix,
+
+    /// A name does not exist on the platform of its prefix, i.e. `win:SIGTERM`.
+    PrefixMismatch,
+}
+
+impl Display for SpecErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::EmptyItem => "empty item",
+            Self::InvalidNumber => "invalid number",
+            Self::OutOfRange => "number out of range",
+            Self::InvalidRange => "range start is greater than its end",
+            Self::UnknownName => "unknown name",
+            Self::UnknownPrefix => "unknown prefix",
+            Self::PrefixMismatch => "name does not match its platform prefix",
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Platform {
+    Unix,
+    Windows,
+}
+
+impl FromStr for ExpectedOutcome {
+    type Err = ParseSpecError;
+
+    /// Parses a spec, i.e. `0,2-5,EX_TEMPFAIL,SIGTERM,!SIGKILL,win:0xC000013A`.
+    ///
+    /// The result is normalized: patterns are sorted, and overlapping or adjacent ranges are
+    /// merged.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = Self::new();
+        if s.trim().is_empty() {
+            return Ok(expected);
+        }
+        let mut offset = 0;
+        for segment in s.split(',') {
+            let (rejected, pattern) = parse_item(segment, offset)?;
+            expected = if rejected {
+                expected.reject(pattern)
+            } else {
+                expected.accept(pattern)
+            };
+            offset += segment.len() + 1;
+        }
+        Ok(expected.normalized())
+    }
+}
+
+fn parse_item(segment: &str, offset: usize) -> Result<(bool, Pattern), ParseSpecError> {
+    let item = segment.trim_start();
+    let mut offset = offset + segment.len() - item.len();
+    let mut item = item.trim_end();
+
+    let rejected = item.starts_with('!');
+    if rejected {
+        item = &item[1..];
+        offset += 1;
+    }
+
+    let (platform, value) = match item.split_once(':') {
+        Some((prefix, value)) => {
+            let platform = match prefix {
+                "unix" => Platform::Unix,
+                "win" => Platform::Windows,
+                _ => {
+                    let span = offset..offset + prefix.len();
+                    return Err(ParseSpecError::new(SpecErrorKind::UnknownPrefix, span));
+                }
+            };
+            offset += prefix.len() + 1;
+            (Some(platform), value)
+        }
+        None => (None, item),
+    };
+
+    let span = offset..offset + value.len();
+    if value.is_empty() {
+        return Err(ParseSpecError::new(SpecErrorKind::EmptyItem, span));
+    }
+    let pattern = if value.starts_with(|c: char| c.is_ascii_digit()) {
+        parse_codes(value, offset, platform)?
+    } else {
+        parse_name(value, platform).map_err(|kind| ParseSpecError::new(kind, span))?
+    };
+    Ok((rejected, pattern))
+}
+
+fn parse_codes(
+    value: &str,
+    offset: usize,
+    platform: Option<Platform>,
+) -> Result<Pattern, ParseSpecError> {
+    let span = offset..offset + value.len();
+    let (min, max) = if let Some((start, end)) = value.split_once('-') {
+        let min = parse_number(start, offset)?;
+        let max = parse_number(end, offset + start.len() + 1)?;
+        if min > max {
+            return Err(ParseSpecError::new(SpecErrorKind::InvalidRange, span));
+        }
+        (min, max)
+    } else {
+        let code = parse_number(value, offset)?;
+        (code, code)
+    };
+    Ok(match platform {
+        None => Pattern::Code { min, max },
+        Some(Platform::Windows) => Pattern::WindowsCode { min, max },
+        Some(Platform::Unix) => {
+            let out_of_range = |_| ParseSpecError::new(SpecErrorKind::OutOfRange, span.clone());
+            Pattern::UnixCode {
+                min: u8::try_from(min).map_err(out_of_range)?,
+                max: u8::try_from(max).map_err(out_of_range)?,
+            }
+        }
+    })
+}
+
+fn parse_number(number: &str, offset: usize) -> Result<u32, ParseSpecError> {
+    let span = offset..offset + number.len();
+    let (digits, radix) = match number
+        .strip_prefix("0x")
+        .or_else(|| number.strip_prefix("0X"))
+    {
+        Some(hex) => (hex, 16),
+        None => (number, 10),
+    };
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return Err(ParseSpecError::new(SpecErrorKind::InvalidNumber, span));
+    }
+    u32::from_str_radix(digits, radix)
+        .map_err(|_| ParseSpecError::new(SpecErrorKind::OutOfRange, span))
+}
+
+fn parse_name(name: &str, platform: Option<Platform>) -> Result<Pattern, SpecErrorKind> {
+    let check = |expected: Platform| match platform {
+        Some(platform) if platform != expected => Err(SpecErrorKind::PrefixMismatch),
+        _ => Ok(()),
+    };
+    if name
+        .get(..3)
+        .is_some_and(|sig| sig.eq_ignore_ascii_case("SIG"))
+    {
+        check(Platform::Unix)?;
+        let number = &name[3..];
+        let signal = match Signal::from_name(name) {
+            Some(signal) => signal,
+            None if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) => {
+                Signal::from_raw(number.parse().map_err(|_| SpecErrorKind::OutOfRange)?)
+            }
+            None => return Err(SpecErrorKind::UnknownName),
+        };
+        return Ok(Pattern::Signal(signal));
+    }
+    if let Some(code) = unix::ExitCode::from_name(name) {
+        check(Platform::Unix)?;
+        let code = code.to_raw();
+        return Ok(Pattern::UnixCode {
+            min: code,
+            max: code,
+        });
+    }
+    if let Some(code) = windows::ExitCode::from_name(name) {
+        check(Platform::Windows)?;
+        let code = code.to_raw();
+        return Ok(Pattern::WindowsCode {
+            min: code,
+            max: code,
+        });
+    }
+    Err(SpecErrorKind::UnknownName)
+}
+
+impl ExpectedOutcome {
+    /// Returns the outcome with its patterns sorted, and overlapping ranges merged.
+    fn normalized(mut self) -> Self {
+        normalize(&mut self.accepted);
+        normalize(&mut self.rejected);
+        self
+    }
+}
+
+fn normalize(patterns: &mut Vec<Pattern>) {
+    patterns.sort_by_key(|pattern| match *pattern {
+        Pattern::Code { min, max } => (0, min, max),
+        Pattern::UnixCode { min, max } => (1, min.into(), max.into()),
+        Pattern::WindowsCode { min, max } => (2, min, max),
+        Pattern::Signal(signal) => (3, signal.to_raw().into(), 0),
+    });
+    patterns.dedup_by(|next, prev| match (prev, *next) {
+        (Pattern::Code { max, .. }, Pattern::Code { min, max: end })
+        | (Pattern::WindowsCode { max, .. }, Pattern::WindowsCode { min, max: end })
+            if min <= max.saturating_add(1) =>
+        {
+            *max = end.max(*max);
+            true
+        }
+        (Pattern::UnixCode { max, .. }, Pattern::UnixCode { min, max: end })
+            if min <= max.saturating_add(1) =>
+        {
+            *max = end.max(*max);
+            true
+        }
+        (Pattern::Signal(signal), Pattern::Signal(next)) => *signal == next,
+        _ => false,
+    });
+}
+
+/// A pattern written as an item of a spec.
+struct Item<'a>(&'a Pattern);
+
+impl Display for Item<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self.0 {
+            Pattern::Code { min, max } if min == max => write!(f, "{min}"),
+            Pattern::Code { min, max } => write!(f, "{min}-{max}"),
+            Pattern::UnixCode { min, max } if min == max => write!(f, "unix:{min}"),
+            Pattern::UnixCode { min, max } => write!(f, "unix:{min}-{max}"),
+            Pattern::WindowsCode { min, max } if min == max => write!(f, "win:{min:#X}"),
+            Pattern::WindowsCode { min, max } => write!(f, "win:{min:#X}-{max:#X}"),
+            Pattern::Signal(signal) => match signal.name() {
+                Some(name) => f.write_str(name),
+                None => write!(f, "SIG{signal}"),
+            },
+        }
+    }
+}
+
+impl Display for ExpectedOutcome {
+    /// Writes the outcome as a normalized spec, which can be parsed back with [`FromStr`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let normalized = self.clone().normalized();
+        let accepted = normalized.accepted.iter().map(|p| ("", p));
+        let rejected = normalized.rejected.iter().map(|p| ("!", p));
+        for (i, (prefix, pattern)) in accepted.chain(rejected).enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{prefix}{}", Item(pattern))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ExpectedOutcome {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ExpectedOutcome {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let spec = alloc::string::String::deserialize(deserializer)?;
+        spec.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ProcResult;
+    use crate::unix::WaitStatus;
+
+    fn parse(spec: &str) -> Result<ExpectedOutcome, ParseSpecError> {
+        spec.parse()
+    }
+
+    fn error(spec: &str) -> (SpecErrorKind, Range<usize>) {
+        let error = parse(spec).unwrap_err();
+        (error.kind(), error.span())
+    }
+
+    #[test]
+    fn test_parse() {
+        let expected = parse("0,2-5,EX_TEMPFAIL,SIGTERM,!SIGKILL,win:0xC000013A").unwrap();
+        assert_eq!(
+            expected,
+            ExpectedOutcome::success()
+                .codes(2..=5)
+                .unix_code(75)
+                .windows_code(0xC000_013A)
+                .signal(Signal::TERMINATION)
+                .reject_signal(Signal::KILL)
+        );
+        assert!(expected.matches(&ProcResult::Unix(WaitStatus::from_raw(0x4B00))));
+        assert!(!expected.matches(&ProcResult::Unix(WaitStatus::from_raw(9))));
+    }
+
+    #[test]
+    fn test_parse_forms() {
+        assert_eq!(parse("").unwrap(), ExpectedOutcome::new());
+        assert_eq!(
+            parse(" unix:3 , win:STATUS_CONTROL_C_EXIT, sig9 ,SIG64").unwrap(),
+            ExpectedOutcome::new()
+                .unix_code(3)
+                .windows_code(0xC000_013A)
+                .signal(Signal::KILL)
+                .signal(Signal::from_raw(64))
+        );
+        assert_eq!(
+            parse("!0x10-0x1F").unwrap(),
+            ExpectedOutcome::new().reject(Pattern::Code { min: 16, max: 31 })
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(error("0,,1"), (SpecErrorKind::EmptyItem, 2..2));
+        assert_eq!(error("0, !"), (SpecErrorKind::EmptyItem, 4..4));
+        assert_eq!(error("0,1x"), (SpecErrorKind::InvalidNumber, 2..4));
+        assert_eq!(error("0,+1"), (SpecErrorKind::UnknownName, 2..4));
+        assert_eq!(error("2-0x"), (SpecErrorKind::InvalidNumber, 2..4));
+        assert_eq!(error("unix:256"), (SpecErrorKind::OutOfRange, 5..8));
+        assert_eq!(error("0x100000000"), (SpecErrorKind::OutOfRange, 0..11));
+        assert_eq!(error("0, 5-2"), (SpecErrorKind::InvalidRange, 3..6));
+        assert_eq!(error("SIGFOO"), (SpecErrorKind::UnknownName, 0..6));
+        assert_eq!(error("0,linux:1"), (SpecErrorKind::UnknownPrefix, 2..7));
+        assert_eq!(
+            error("!win:SIGTERM"),
+            (SpecErrorKind::PrefixMismatch, 5..12)
+        );
+        assert_eq!(
+            error("unix:STATUS_ACCESS_VIOLATION"),
+            (SpecErrorKind::PrefixMismatch, 5..28)
+        );
+        assert_eq!(
+            parse("0,linux:1").unwrap_err().to_string(),
+            "unknown prefix at 2..7"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let expected = parse("0,2-5,EX_TEMPFAIL,SIGTERM,!SIGKILL,win:0xC000013A").unwrap();
+        assert_eq!(
+            expected.to_string(),
+            "0,2-5,unix:75,win:0xC000013A,SIGTERM,!SIGKILL"
+        );
+        assert_eq!(parse(&expected.to_string()).unwrap(), expected);
+        assert_eq!(ExpectedOutcome::new().to_string(), "");
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            parse("SIGTERM,5-9,1,3-4,2,SIGTERM,unix:1-2,unix:3,SIG64,!7")
+                .unwrap()
+                .to_string(),
+            "1-9,unix:1-3,SIGTERM,SIG64,!7"
+        );
+        assert_eq!(
+            ExpectedOutcome::new()
+                .code(u32::MAX)
+                .codes(0..=u32::MAX)
+                .to_string(),
+            "0-4294967295"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_serde() {
+        let expected: ExpectedOutcome = serde_json::from_str("\"0, 1, !SIGKILL\"").unwrap();
+        assert_eq!(
+            expected,
+            ExpectedOutcome::new()
+                .codes(0..=1)
+                .reject_signal(Signal::KILL)
+        );
+        assert_eq!(
+            serde_json::to_string(&expected).unwrap(),
+            "\"0-1,!SIGKILL\""
+        );
+
+        let error = serde_json::from_str::<ExpectedOutcome>("\"0,SIGFOO\"").unwrap_err();
+        assert_eq!(error.to_string(), "unknown name at 2..8");
+    }
+}
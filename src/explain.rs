@@ -0,0 +1,590 @@
+//! Diagnostic explanations of exit codes, signals and results.
+//!
+//! An [`Explanation`] describes what a result most likely means, why it probably happened, and
+//! what to check next. Explanations are looked up in static tables by the `explain()` methods of
+//! [`ProcResult`](crate::ProcResult) and the platform types:
+//!
+//! ```rust
+//! use proc_result::ProcResult;
+//! use proc_result::unix::{ExitCode, WaitStatus};
+//!
+//! let result = ProcResult::Unix(WaitStatus::from_raw(0x7F00));
+//! let explanation = result.explain().unwrap();
+//! assert_eq!(explanation.summary(), "command not found");
+//! assert!(explanation.hints()[0].contains("PATH"));
+//!
+//! // A shell reports a termination by signal `N` as exit code `128 + N`.
+//! let explanation = ExitCode::from_raw(137).explain().unwrap();
+//! assert_eq!(explanation.summary(), "killed by SIGKILL");
+//! ```
+
+use core::fmt::Display;
+
+use crate::unix::{ExitCode as UnixExitCode, Signal};
+use crate::windows::ExitCode as WindowsExitCode;
+
+/// A diagnostic explanation of a result, with probable causes and remediation hints.
+///
+/// Displays as a short report:
+///
+/// ```text
+/// killed by SIGKILL
+/// Probable causes:
+/// - the kernel's out-of-memory (OOM) killer ended the process
+/// ...
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Explanation {
+    summary: &'static str,
+    causes: &'static [&'static str],
+    hints: &'static [&'static str],
+}
+
+impl Explanation {
+    const fn new(
+        summary: &'static str,
+        causes: &'static [&'static str],
+        hints: &'static [&'static str],
+    ) -> Self {
+        Self {
+            summary,
+            causes,
+            hints,
+        }
+    }
+
+    /// Returns a short summary of what happened, i.e. `command not found`.
+    #[must_use]
+    pub const fn summary(&self) -> &'static str {
+        self.summary
+    }
+
+    /// Returns the probable causes, most likely first.
+    #[must_use]
+    pub const fn causes(&self) -> &'static [&'static str] {
+        self.causes
+    }
+
+    /// Returns hints on how to investigate or fix the problem.
+    #[must_use]
+    pub const fn hints(&self) -> &'static [&'static str] {
+        self.hints
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.summary)?;
+        if !self.causes.is_empty() {
+            f.write_str("\nProbable causes:")?;
+            for cause in self.causes {
+                write!(f, "\n- {cause}")?;
+            }
+        }
+        if !self.hints.is_empty() {
+            f.write_str("\nHints:")?;
+            for hint in self.hints {
+                write!(f, "\n- {hint}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the explanation of a Unix exit code.
+///
+/// Exit codes `129` to `159` are explained as the signal a shell reports them for.
+pub(crate) fn unix_exit_code(code: UnixExitCode) -> Option<Explanation> {
+    match code.to_raw() {
+        raw @ 129..=159 => signal(Signal::from_raw(raw - 128)),
+        raw => lookup(UNIX_EXIT_CODES, &raw),
+    }
+}
+
+/// Returns the explanation of a Unix signal that terminated a process.
+pub(crate) fn signal(signal: Signal) -> Option<Explanation> {
+    lookup(SIGNALS, &signal.to_raw())
+}
+
+/// Returns the explanation of a Windows exit code.
+pub(crate) fn windows_exit_code(code: WindowsExitCode) -> Option<Explanation> {
+    lookup(WINDOWS_EXIT_CODES, &code.to_raw())
+}
+
+fn lookup<T: PartialEq>(table: &[(T, Explanation)], code: &T) -> Option<Explanation> {
+    table
+        .iter()
+        .find(|(c, _)| c == code)
+        .map(|(_, explanation)| *explanation)
+}
+
+/// The explanation of exit code `127`, also used for programs that were not found.
+pub(crate) const COMMAND_NOT_FOUND: Explanation = Explanation::new(
+    "command not found",
+    &[
+        "the program is not installed",
+        "the program's directory is not in PATH",
+        "the command name is misspelled",
+    ],
+    &["check PATH, or run the program by its absolute path"],
+);
+
+/// The explanation of exit code `126`, also used for programs that could not be executed.
+pub(crate) const COMMAND_CANNOT_EXECUTE: Explanation = Explanation::new(
+    "command found but could not be executed",
+    &[
+        "the file is not executable",
+        "the script's interpreter (shebang line) does not exist",
+        "the binary was built for another architecture or platform",
+    ],
+    &[
+        "check the file's permissions, i.e. `chmod +x`",
+        "check the first line of the script, and that its line endings are not CRLF",
+    ],
+);
+
+/// The explanation of exit code `124`, also used for results that timed out.
+pub(crate) const TIMED_OUT: Explanation = Explanation::new(
+    "timed out",
+    &["the command ran longer than its time limit, i.e. one set by `timeout(1)`"],
+    &[
+        "check whether the command hangs, i.e. waiting on input or a lock",
+        "raise the time limit if the command is just slow",
+    ],
+);
+
+const UNIX_EXIT_CODES: &[(u8, Explanation)] = &[
+    (
+        2,
+        Explanation::new(
+            "invalid usage",
+            &["a shell builtin or program was called with invalid arguments"],
+            &["check the arguments against the program's usage"],
+        ),
+    ),
+    (
+        64,
+        Explanation::new(
+            "invalid usage (EX_USAGE)",
+            &["the program was called with invalid arguments"],
+            &["check the arguments against the program's usage"],
+        ),
+    ),
+    (
+        65,
+        Explanation::new(
+            "invalid input data (EX_DATAERR)",
+            &["the input was malformed"],
+            &["validate the input the program was given"],
+        ),
+    ),
+    (
+        66,
+        Explanation::new(
+            "input file missing (EX_NOINPUT)",
+            &["an input file does not exist or is not readable"],
+            &["check the paths passed to the program"],
+        ),
+    ),
+    (
+        69,
+        Explanation::new(
+            "service unavailable (EX_UNAVAILABLE)",
+            &["a service or resource the program needs is not available"],
+            &["check that required services are running and reachable"],
+        ),
+    ),
+    (
+        70,
+        Explanation::new(
+            "internal software error (EX_SOFTWARE)",
+            &["the program detected a bug in itself"],
+            &["report the problem to the program's maintainers"],
+        ),
+    ),
+    (
+        73,
+        Explanation::new(
+            "cannot create output file (EX_CANTCREAT)",
+            &["an output file could not be created"],
+            &["check that the output directory exists and is writable"],
+        ),
+    ),
+    (
+        74,
+        Explanation::new(
+            "input/output error (EX_IOERR)",
+            &["reading or writing a file failed", "the disk is full"],
+            &["check free disk space and the health of the file system"],
+        ),
+    ),
+    (
+        75,
+        Explanation::new(
+            "temporary failure (EX_TEMPFAIL)",
+            &["a transient condition, i.e. a network hiccup or a busy resource"],
+            &["retry the command later"],
+        ),
+    ),
+    (
+        77,
+        Explanation::new(
+            "permission denied (EX_NOPERM)",
+            &["the user lacks permission for the operation"],
+            &["check the user the program runs as, and the permissions it needs"],
+        ),
+    ),
+    (
+        78,
+        Explanation::new(
+            "configuration error (EX_CONFIG)",
+            &["a configuration file is missing or invalid"],
+            &["check the program's configuration"],
+        ),
+    ),
+    (124, TIMED_OUT),
+    (126, COMMAND_CANNOT_EXECUTE),
+    (127, COMMAND_NOT_FOUND),
+];
+
+const SIGNALS: &[(u8, Explanation)] = &[
+    (
+        1,
+        Explanation::new(
+            "hung up by SIGHUP",
+            &["the controlling terminal or SSH session was closed"],
+            &["run long-lived commands with `nohup`, or in `tmux` or `screen`"],
+        ),
+    ),
+    (
+        2,
+        Explanation::new(
+            "interrupted by SIGINT",
+            &[
+                "the user pressed Ctrl+C",
+                "a supervisor forwarded an interrupt",
+            ],
+            &[],
+        ),
+    ),
+    (
+        3,
+        Explanation::new("quit by SIGQUIT", &["the user pressed Ctrl+\\"], &[]),
+    ),
+    (
+        4,
+        Explanation::new(
+            "crashed with SIGILL",
+            &[
+                "the binary uses CPU instructions the machine does not support",
+                "a deliberate trap, i.e. Rust's `core::intrinsics::abort` or a failed check",
+            ],
+            &["check that the binary was built for this CPU, i.e. `-C target-cpu`"],
+        ),
+    ),
+    (
+        5,
+        Explanation::new(
+            "crashed with SIGTRAP",
+            &["a breakpoint or debug trap was hit without a debugger attached"],
+            &["run the program under a debugger to see where the trap occurs"],
+        ),
+    ),
+    (
+        6,
+        Explanation::new(
+            "aborted by SIGABRT",
+            &[
+                "the program called `abort()`, i.e. after a failed assertion",
+                "a Rust panic with `panic = \"abort\"`, or a panic while panicking",
+                "the allocator detected heap corruption",
+            ],
+            &["check the program's standard error for an assertion or panic message"],
+        ),
+    ),
+    (
+        7,
+        Explanation::new(
+            "crashed with SIGBUS",
+            &[
+                "a memory-mapped file was truncated while in use",
+                "a misaligned memory access",
+            ],
+            &["check whether files mapped by the program are modified concurrently"],
+        ),
+    ),
+    (
+        8,
+        Explanation::new(
+            "crashed with SIGFPE",
+            &["an integer division by zero or overflow"],
+            &["check arithmetic on untrusted or unexpected input"],
+        ),
+    ),
+    (
+        9,
+        Explanation::new(
+            "killed by SIGKILL",
+            &[
+                "the kernel's out-of-memory (OOM) killer ended the process",
+                "a supervisor or container runtime killed it, i.e. after a timeout",
+                "a user ran `kill -9`",
+            ],
+            &[
+                "check `dmesg` or `journalctl -k` for OOM killer messages",
+                "check the memory limits of the container or cgroup",
+            ],
+        ),
+    ),
+    (
+        11,
+        Explanation::new(
+            "crashed with SIGSEGV",
+            &[
+                "an invalid memory access, i.e. a null or dangling pointer",
+                "a stack overflow, i.e. from unbounded recursion",
+            ],
+            &[
+                "run the program under a debugger, or inspect the core dump",
+                "raise the stack size limit (`ulimit -s`) to rule out a stack overflow",
+            ],
+        ),
+    ),
+    (
+        13,
+        Explanation::new(
+            "terminated by SIGPIPE",
+            &["the reader of the program's output closed early, i.e. `| head`"],
+            &["usually harmless in a pipeline; see `set -o pipefail`"],
+        ),
+    ),
+    (
+        14,
+        Explanation::new(
+            "terminated by SIGALRM",
+            &["a timer set with `alarm()` expired, i.e. a time limit"],
+            &[],
+        ),
+    ),
+    (
+        15,
+        Explanation::new(
+            "terminated by SIGTERM",
+            &[
+                "a supervisor asked the process to stop, i.e. on shutdown or a timeout",
+                "a user ran `kill`",
+            ],
+            &["check the logs of the supervisor, i.e. systemd or the container runtime"],
+        ),
+    ),
+    (
+        24,
+        Explanation::new(
+            "terminated by SIGXCPU",
+            &["the process exceeded its CPU time limit"],
+            &["check `ulimit -t`"],
+        ),
+    ),
+    (
+        25,
+        Explanation::new(
+            "terminated by SIGXFSZ",
+            &["the process exceeded its file size limit"],
+            &["check `ulimit -f`"],
+        ),
+    ),
+    (
+        31,
+        Explanation::new(
+            "terminated by SIGSYS",
+            &["a seccomp filter rejected a system call"],
+            &["check the sandbox or container's seccomp profile"],
+        ),
+    ),
+];
+
+const WINDOWS_EXIT_CODES: &[(u32, Explanation)] = &[
+    (
+        2,
+        Explanation::new(
+            "file not found (ERROR_FILE_NOT_FOUND)",
+            &["a file the program needs does not exist"],
+            &["check the paths passed to the program"],
+        ),
+    ),
+    (
+        5,
+        Explanation::new(
+            "access denied (ERROR_ACCESS_DENIED)",
+            &[
+                "the user lacks permission for the operation",
+                "a file is locked",
+            ],
+            &["check permissions, or run the program elevated"],
+        ),
+    ),
+    (
+        259,
+        Explanation::new(
+            "still active (STILL_ACTIVE)",
+            &["the process had not exited when its exit code was read"],
+            &["wait for the process to exit before reading its exit code"],
+        ),
+    ),
+    (9009, COMMAND_NOT_FOUND),
+    (
+        0xC000_0005,
+        Explanation::new(
+            "crashed with an access violation (STATUS_ACCESS_VIOLATION)",
+            &["an invalid memory access, i.e. a null or dangling pointer"],
+            &["run the program under a debugger, or collect a crash dump"],
+        ),
+    ),
+    (
+        0xC000_0017,
+        Explanation::new(
+            "out of memory (STATUS_NO_MEMORY)",
+            &["the system or the process's job object ran out of memory"],
+            &["check the memory limits of the job object or container"],
+        ),
+    ),
+    (
+        0xC000_007B,
+        Explanation::new(
+            "invalid executable image (STATUS_INVALID_IMAGE_FORMAT)",
+            &["a 32-bit process loaded a 64-bit DLL, or vice versa"],
+            &["check that the program and its DLLs are built for the same architecture"],
+        ),
+    ),
+    (
+        0xC000_00FD,
+        Explanation::new(
+            "crashed with a stack overflow (STATUS_STACK_OVERFLOW)",
+            &["unbounded recursion", "large values allocated on the stack"],
+            &["run the program under a debugger to find the recursion"],
+        ),
+    ),
+    (
+        0xC000_0135,
+        Explanation::new(
+            "a required DLL was not found (STATUS_DLL_NOT_FOUND)",
+            &[
+                "a DLL the program depends on is not installed",
+                "the DLL's directory is not in PATH",
+            ],
+            &[
+                "install the program's runtime, i.e. the Visual C++ Redistributable",
+                "list the program's dependencies, i.e. with `dumpbin /dependents`",
+            ],
+        ),
+    ),
+    (
+        0xC000_0139,
+        Explanation::new(
+            "a DLL entry point was not found (STATUS_ENTRYPOINT_NOT_FOUND)",
+            &["a DLL the program depends on is an incompatible version"],
+            &["check which copy of the DLL is loaded, i.e. a stale one earlier in PATH"],
+        ),
+    ),
+    (
+        0xC000_013A,
+        Explanation::new(
+            "interrupted by Ctrl+C (STATUS_CONTROL_C_EXIT)",
+            &["the user pressed Ctrl+C or closed the console"],
+            &[],
+        ),
+    ),
+    (
+        0xC000_0142,
+        Explanation::new(
+            "a DLL failed to initialize (STATUS_DLL_INIT_FAILED)",
+            &[
+                "a DLL's initialization routine failed",
+                "the process was started in a non-interactive session without a desktop",
+            ],
+            &["check the Application event log for details"],
+        ),
+    ),
+    (
+        0xC000_0409,
+        Explanation::new(
+            "fail-fast termination (STATUS_STACK_BUFFER_OVERRUN)",
+            &[
+                "the program requested immediate termination, i.e. Rust's `abort()`",
+                "a stack buffer overrun or other corruption was detected",
+            ],
+            &["check the program's standard error, or collect a crash dump"],
+        ),
+    ),
+    (
+        0xE043_4352,
+        Explanation::new(
+            "unhandled .NET exception",
+            &["the program threw an exception that was not caught"],
+            &["check the program's standard error or the Application event log"],
+        ),
+    ),
+    (
+        0xE06D_7363,
+        Explanation::new(
+            "unhandled C++ exception",
+            &["the program threw an exception that was not caught"],
+            &["run the program under a debugger to see the exception"],
+        ),
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unix_exit_code() {
+        let explanation = unix_exit_code(UnixExitCode::COMMAND_NOT_FOUND).unwrap();
+        assert_eq!(explanation, COMMAND_NOT_FOUND);
+
+        let explanation = unix_exit_code(UnixExitCode::from_raw(137)).unwrap();
+        assert_eq!(explanation.summary(), "killed by SIGKILL");
+        assert!(explanation.causes()[0].contains("OOM"));
+
+        assert_eq!(unix_exit_code(UnixExitCode::GENERAL_ERROR), None);
+    }
+
+    #[test]
+    fn test_signal() {
+        let explanation = signal(Signal::BROKEN_PIPE).unwrap();
+        assert!(explanation.causes()[0].contains("closed early"));
+        assert_eq!(signal(Signal::from_raw(64)), None);
+    }
+
+    #[test]
+    fn test_windows_exit_code() {
+        let explanation = windows_exit_code(WindowsExitCode::from_raw(0xC000_0135)).unwrap();
+        assert!(explanation.summary().contains("DLL"));
+        assert_eq!(windows_exit_code(WindowsExitCode::GENERAL_ERROR), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let explanation = Explanation::new("summary", &["cause"], &[]);
+        assert_eq!(
+            explanation.to_string(),
+            "summary\nProbable causes:\n- cause"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn test_serialize() {
+        let explanation = Explanation::new("summary", &["cause"], &["hint"]);
+        assert_eq!(
+            serde_json::to_string(&explanation).unwrap(),
+            r#"{"summary":"summary","causes":["cause"],"hints":["hint"]}"#
+        );
+    }
+}
@@ -0,0 +1,607 @@
+use core::fmt::{Display, Formatter, Result};
+use core::str::FromStr;
+use core::time::Duration;
+
+use crate::unix::{Signal, WaitState, WaitStatus};
+use crate::windows::{ExitCode, ExitCodeFormat};
+use crate::{ProcResult, SpawnError, SpawnErrorKind};
+
+/// Styles in which a [`ProcResult`] can be formatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ResultFormat {
+    /// A description of what happened, i.e. `killed by signal SIGKILL (9)`.
+    ///
+    /// This is the same as the [`Display`] implementation of [`ProcResult`].
+    #[default]
+    Descriptive,
+
+    /// The status of a job as reported by `bash`, i.e. `Exit 1`, or
+    /// `Segmentation fault (core dumped)`.
+    ///
+    /// Programs that could not be started are reported with the exit code `bash` uses for them
+    /// (`127` if not found, `126` otherwise), and timeouts with the exit code of `timeout(1)`
+    /// (`124`).
+    Bash,
+
+    /// The message of Python's `subprocess.CalledProcessError`, i.e.
+    /// `Command died with <Signals.SIGKILL: 9>.`
+    Python,
+
+    /// The [`Display`] implementation of `std::process::ExitStatus`, i.e. `signal: 9 (SIGKILL)`.
+    Std,
+
+    /// A compact and stable form, i.e. `unix:signal:SIGSEGV:core`, that is parsed back exactly
+    /// by the [`FromStr`] implementation of [`ProcResult`].
+    ///
+    /// This is the same as the alternate [`Display`] implementation (`{:#}`) of [`ProcResult`].
+    ///
+    /// | Result                 | Formatted as                                      |
+    /// |------------------------|---------------------------------------------------|
+    /// | Exited with code `1`   | `unix:exit:1`                                     |
+    /// | Terminated by a signal | `unix:signal:SIGKILL`, or `unix:signal:64`        |
+    /// | ... with a core dump   | `unix:signal:SIGSEGV:core`                        |
+    /// | Stopped by a signal    | `unix:stopped:SIGTSTP`                            |
+    /// | Continued              | `unix:continued`                                  |
+    /// | Any other wait status  | `unix:raw:65792`                                  |
+    /// | Windows exit code      | `windows:0xC0000005`                              |
+    /// | Not started            | `not_started:not_found:2`, or `not_started:other` |
+    /// | Timed out              | `timed_out:1.5s`, or `timed_out`                  |
+    Canonical,
+}
+
+/// Displays a [`ProcResult`] in a specific [`ResultFormat`].
+///
+/// Created by [`ProcResult::format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormattedResult {
+    result: ProcResult,
+    format: ResultFormat,
+}
+
+impl FormattedResult {
+    pub(crate) const fn new(result: ProcResult, format: ResultFormat) -> Self {
+        Self { result, format }
+    }
+}
+
+impl Display for FormattedResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.format {
+            ResultFormat::Descriptive => descriptive(f, &self.result),
+            ResultFormat::Bash => bash(f, &self.result),
+            ResultFormat::Python => python(f, &self.result),
+            ResultFormat::Std => std(f, &self.result),
+            ResultFormat::Canonical => canonical(f, &self.result),
+        }
+    }
+}
+
+fn descriptive(f: &mut Formatter<'_>, result: &ProcResult) -> Result {
+    match result {
+        ProcResult::Unix(status) => write!(f, "{status}"),
+        ProcResult::Windows(code) if code.to_raw() <= 0xFFFF => {
+            write!(f, "exited with code {code}")
+        }
+        ProcResult::Windows(code) => match code.name() {
+            Some(name) => write!(
+                f,
+                "exited with {} ({name})",
+                code.format(ExitCodeFormat::Hex)
+            ),
+            None => write!(f, "exited with {}", code.format(ExitCodeFormat::Hex)),
+        },
+        ProcResult::NotStarted(error) => write!(f, "failed to start: {error}"),
+        ProcResult::TimedOut(limit) => timed_out(f, *limit),
+    }
+}
+
+fn bash(f: &mut Formatter<'_>, result: &ProcResult) -> Result {
+    match result {
+        ProcResult::Unix(status) => match status.state() {
+            WaitState::Exited { exit_code } if exit_code.is_success() => f.write_str("Done"),
+            WaitState::Exited { exit_code } => write!(f, "Exit {exit_code}"),
+            WaitState::Signaled { signal, core_dump } => {
+                match signal.description() {
+                    Some(description) => f.write_str(description)?,
+                    None => write!(f, "Signal {signal}")?,
+                }
+                if core_dump {
+                    f.write_str(" (core dumped)")?;
+                }
+                Ok(())
+            }
+            WaitState::Stopped { signal } => f.write_str(signal.description().unwrap_or("Stopped")),
+            WaitState::Continued => f.write_str("Running"),
+            WaitState::Unsupported(_) => f.write_str("Unknown status"),
+        },
+        ProcResult::Windows(code) if code.is_success() => f.write_str("Done"),
+        ProcResult::Windows(code) => write!(f, "Exit {code}"),
+        ProcResult::NotStarted(error) if matches!(error.kind(), SpawnErrorKind::NotFound) => {
+            f.write_str("Exit 127")
+        }
+        ProcResult::NotStarted(_) => f.write_str("Exit 126"),
+        ProcResult::TimedOut(_) => f.write_str("Exit 124"),
+    }
+}
+
+fn python(f: &mut Formatter<'_>, result: &ProcResult) -> Result {
+    fn signal(f: &mut Formatter<'_>, signal: Signal) -> Result {
+        match signal.name() {
+            Some(name) => write!(f, "<Signals.{name}: {signal}>"),
+            None => write!(f, "unknown signal {signal}"),
+        }
+    }
+
+    match result {
+        ProcResult::Unix(status) => match status.state() {
+            WaitState::Exited { exit_code } if exit_code.is_success() => {
+                f.write_str("Command returned exit status 0.")
+            }
+            WaitState::Exited { exit_code } => {
+                write!(f, "Command returned non-zero exit status {exit_code}.")
+            }
+            WaitState::Signaled { signal: s, .. } => {
+                f.write_str("Command died with ")?;
+                signal(f, s)?;
+                f.write_str(".")
+            }
+            WaitState::Stopped { signal: s } => {
+                f.write_str("Command was stopped by ")?;
+                signal(f, s)?;
+                f.write_str(".")
+            }
+            WaitState::Continued => f.write_str("Command was continued."),
+            WaitState::Unsupported(status) => {
+                write!(f, "Command returned unrecognized wait status {status:#x}.")
+            }
+        },
+        ProcResult::Windows(code) if code.is_success() => {
+            f.write_str("Command returned exit status 0.")
+        }
+        ProcResult::Windows(code) => write!(f, "Command returned non-zero exit status {code}."),
+        ProcResult::NotStarted(error) => match error.raw_os_error() {
+            Some(errno) => write!(f, "[Errno {errno}] {}", error.kind()),
+            None => write!(f, "{}", error.kind()),
+        },
+        ProcResult::TimedOut(Some(limit)) if limit.subsec_nanos() == 0 => {
+            write!(f, "Command timed out after {} seconds", limit.as_secs())
+        }
+        ProcResult::TimedOut(Some(limit)) => {
+            write!(f, "Command timed out after {} seconds", limit.as_secs_f64())
+        }
+        ProcResult::TimedOut(None) => f.write_str("Command timed out"),
+    }
+}
+
+fn std(f: &mut Formatter<'_>, result: &ProcResult) -> Result {
+    match result {
+        ProcResult::Unix(status) => std_unix(f, *status),
+        ProcResult::Windows(code) => std_windows(f, *code),
+        ProcResult::NotStarted(error) => write!(f, "{error}"),
+        ProcResult::TimedOut(limit) => timed_out(f, *limit),
+    }
+}
+
+fn std_unix(f: &mut Formatter<'_>, status: WaitStatus) -> Result {
+    match status.state() {
+        WaitState::Exited { exit_code } => write!(f, "exit status: {exit_code}"),
+        WaitState::Signaled { signal, core_dump } => {
+            write!(f, "signal: {signal}")?;
+            if let Some(name) = signal.name() {
+                write!(f, " ({name})")?;
+            }
+            if core_dump {
+                f.write_str(" (core dumped)")?;
+            }
+            Ok(())
+        }
+        WaitState::Stopped { signal } => {
+            write!(f, "stopped (not terminated) by signal: {signal}")?;
+            if let Some(name) = signal.name() {
+                write!(f, " ({name})")?;
+            }
+            Ok(())
+        }
+        WaitState::Continued => f.write_str("continued (WIFCONTINUED)"),
+        WaitState::Unsupported(status) => {
+            write!(f, "unrecognised wait status: {status} {status:#x}")
+        }
+    }
+}
+
+fn std_windows(f: &mut Formatter<'_>, code: ExitCode) -> Result {
+    // Like std, print exit codes with the high bit set (i.e. exceptions) in hexadecimal.
+    if code.to_raw() & 0x8000_0000 == 0 {
+        write!(f, "exit code: {code}")
+    } else {
+        write!(f, "exit code: {:#x}", code.to_raw())
+    }
+}
+
+fn canonical(f: &mut Formatter<'_>, result: &ProcResult) -> Result {
+    fn signal(f: &mut Formatter<'_>, signal: Signal) -> Result {
+        match signal.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{signal}"),
+        }
+    }
+
+    match result {
+        // Statuses that would not be recreated exactly from their state are written raw.
+        ProcResult::Unix(status) if status.state().to_raw() != status.to_raw() => {
+            write!(f, "unix:raw:{}", status.to_raw())
+        }
+        ProcResult::Unix(status) => match status.state() {
+            WaitState::Exited { exit_code } => write!(f, "unix:exit:{exit_code}"),
+            WaitState::Signaled {
+                signal: s,
+                core_dump,
+            } => {
+                f.write_str("unix:signal:")?;
+                signal(f, s)?;
+                if core_dump {
+                    f.write_str(":core")?;
+                }
+                Ok(())
+            }
+            WaitState::Stopped { signal: s } => {
+                f.write_str("unix:stopped:")?;
+                signal(f, s)
+            }
+            WaitState::Continued => f.write_str("unix:continued"),
+            WaitState::Unsupported(status) => write!(f, "unix:raw:{status}"),
+        },
+        ProcResult::Windows(code) => write!(f, "windows:{}", code.format(ExitCodeFormat::Hex)),
+        ProcResult::NotStarted(error) => {
+            write!(f, "not_started:{}", spawn_error_kind_name(error.kind()))?;
+            match error.raw_os_error() {
+                Some(errno) => write!(f, ":{errno}"),
+                None => Ok(()),
+            }
+        }
+        ProcResult::TimedOut(None) => f.write_str("timed_out"),
+        ProcResult::TimedOut(Some(limit)) => {
+            write!(f, "timed_out:{}", limit.as_secs())?;
+            let mut nanos = limit.subsec_nanos();
+            if nanos > 0 {
+                let mut digits = 9;
+                while nanos % 10 == 0 {
+                    nanos /= 10;
+                    digits -= 1;
+                }
+                write!(f, ".{nanos:0digits$}")?;
+            }
+            f.write_str("s")
+        }
+    }
+}
+
+const fn spawn_error_kind_name(kind: SpawnErrorKind) -> &'static str {
+    match kind {
+        SpawnErrorKind::NotFound => "not_found",
+        SpawnErrorKind::PermissionDenied => "permission_denied",
+        SpawnErrorKind::ExecFormat => "exec_format",
+        SpawnErrorKind::Other => "other",
+    }
+}
+
+fn timed_out(f: &mut Formatter<'_>, limit: Option<Duration>) -> Result {
+    match limit {
+        Some(limit) => write!(f, "timed out after {limit:?}"),
+        None => f.write_str("timed out"),
+    }
+}
+
+/// An error which can be returned when parsing a [`ProcResult`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseResultError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    UnknownForm,
+    InvalidNumber,
+    UnknownName,
+    InvalidSignal,
+}
+
+impl ParseResultError {
+    const fn new(kind: ParseErrorKind) -> Self {
+        Self { kind }
+    }
+}
+
+impl Display for ParseResultError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::UnknownForm => "unrecognized process result",
+            ParseErrorKind::InvalidNumber => "invalid number found in process result",
+            ParseErrorKind::UnknownName => "unknown name found in process result",
+            ParseErrorKind::InvalidSignal => "invalid signal found in process result",
+        })
+    }
+}
+
+impl core::error::Error for ParseResultError {}
+
+impl FromStr for ProcResult {
+    type Err = ParseResultError;
+
+    /// Parses a result in the [`ResultFormat::Canonical`] form, i.e. `unix:exit:1`.
+    ///
+    /// Signals are accepted by name or number, and Windows exit codes in any form accepted by
+    /// the [`FromStr`] implementation of [`ExitCode`]. Signals that a wait status cannot hold,
+    /// i.e. `unix:signal:0`, which would be read back as an exit, are rejected.
+    fn from_str(s: &str) -> core::result::Result<Self, Self::Err> {
+        use ParseErrorKind::{InvalidNumber, InvalidSignal, UnknownForm, UnknownName};
+
+        fn number<T: FromStr>(s: &str) -> core::result::Result<T, ParseResultError> {
+            s.parse().map_err(|_| ParseResultError::new(InvalidNumber))
+        }
+
+        fn signal(s: &str) -> core::result::Result<Signal, ParseResultError> {
+            if s.starts_with(|c: char| c.is_ascii_digit()) {
+                return number(s).map(Signal::from_raw);
+            }
+            Signal::from_name(s).ok_or(ParseResultError::new(UnknownName))
+        }
+
+        let unix = |state: WaitState| {
+            if !state.is_encodable() {
+                return Err(ParseResultError::new(InvalidSignal));
+            }
+            Ok(Self::Unix(WaitStatus::from_raw(state.to_raw())))
+        };
+        let mut parts = s.split(':');
+        let mut next = || parts.next();
+        match (next(), next(), next(), next(), next()) {
+            (Some("unix"), Some("exit"), Some(code), None, None) => unix(WaitState::Exited {
+                exit_code: number::<u8>(code)?.into(),
+            }),
+            (Some("unix"), Some("signal"), Some(s), core, None)
+                if matches!(core, None | Some("core")) =>
+            {
+                unix(WaitState::Signaled {
+                    signal: signal(s)?,
+                    core_dump: core.is_some(),
+                })
+            }
+            (Some("unix"), Some("stopped"), Some(s), None, None) => {
+                unix(WaitState::Stopped { signal: signal(s)? })
+            }
+            (Some("unix"), Some("continued"), None, None, None) => unix(WaitState::Continued),
+            (Some("unix"), Some("raw"), Some(raw), None, None) => {
+                Ok(Self::Unix(WaitStatus::from_raw(number(raw)?)))
+            }
+            (Some("windows"), Some(code), None, None, None) => code
+                .parse()
+                .map(Self::Windows)
+                .map_err(|_| ParseResultError::new(InvalidNumber)),
+            (Some("not_started"), Some(kind), errno, None, None) => {
+                let kind = [
+                    SpawnErrorKind::NotFound,
+                    SpawnErrorKind::PermissionDenied,
+                    SpawnErrorKind::ExecFormat,
+                    SpawnErrorKind::Other,
+                ]
+                .into_iter()
+                .find(|k| spawn_error_kind_name(*k) == kind)
+                .ok_or(ParseResultError::new(UnknownName))?;
+                let errno = errno.map(number).transpose()?;
+                Ok(Self::NotStarted(SpawnError::new(kind, errno)))
+            }
+            (Some("timed_out"), None, None, None, None) => Ok(Self::TimedOut(None)),
+            (Some("timed_out"), Some(limit), None, None, None) => {
+                let limit = limit
+                    .strip_suffix('s')
+                    .ok_or(ParseResultError::new(InvalidNumber))?;
+                let (secs, fraction) = limit.split_once('.').unwrap_or((limit, ""));
+                if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(ParseResultError::new(InvalidNumber));
+                }
+                let mut nanos = 0;
+                for digit in fraction.bytes().chain(core::iter::repeat(b'0')).take(9) {
+                    nanos = nanos * 10 + u32::from(digit - b'0');
+                }
+                Ok(Self::TimedOut(Some(Duration::new(number(secs)?, nanos))))
+            }
+            _ => Err(ParseResultError::new(UnknownForm)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SpawnError, unix};
+
+    fn format(result: ProcResult, format: ResultFormat) -> String {
+        result.format(format).to_string()
+    }
+
+    fn unix(raw: i32) -> ProcResult {
+        ProcResult::Unix(unix::WaitStatus::from_raw(raw))
+    }
+
+    fn windows(raw: u32) -> ProcResult {
+        ProcResult::Windows(ExitCode::from_raw(raw))
+    }
+
+    #[test]
+    fn test_descriptive() {
+        let descriptive = |result| format(result, ResultFormat::Descriptive);
+        assert_eq!(descriptive(unix(0x0100)), "exited with code 1");
+        assert_eq!(descriptive(unix(9)), "killed by signal SIGKILL (9)");
+        assert_eq!(descriptive(windows(1)), "exited with code 1");
+        assert_eq!(
+            descriptive(windows(0xC000_0005)),
+            "exited with 0xC0000005 (STATUS_ACCESS_VIOLATION)"
+        );
+        assert_eq!(descriptive(windows(0xC000_0135)), "exited with 0xC0000135");
+        assert_eq!(
+            descriptive(ProcResult::TimedOut(Some(Duration::from_millis(1500)))),
+            "timed out after 1.5s"
+        );
+    }
+
+    #[test]
+    fn test_bash() {
+        let bash = |result| format(result, ResultFormat::Bash);
+        assert_eq!(bash(unix(0)), "Done");
+        assert_eq!(bash(unix(0x0100)), "Exit 1");
+        assert_eq!(bash(unix(9)), "Killed");
+        assert_eq!(bash(unix(0x8B)), "Segmentation fault (core dumped)");
+        assert_eq!(bash(unix(0x147F)), "Stopped");
+        assert_eq!(bash(windows(2)), "Exit 2");
+
+        let not_found = SpawnError::new(SpawnErrorKind::NotFound, None);
+        assert_eq!(bash(ProcResult::NotStarted(not_found)), "Exit 127");
+        assert_eq!(bash(ProcResult::TimedOut(None)), "Exit 124");
+    }
+
+    #[test]
+    fn test_python() {
+        let python = |result| format(result, ResultFormat::Python);
+        assert_eq!(
+            python(unix(0x0100)),
+            "Command returned non-zero exit status 1."
+        );
+        assert_eq!(python(unix(9)), "Command died with <Signals.SIGKILL: 9>.");
+        assert_eq!(python(unix(0x40)), "Command died with unknown signal 64.");
+        assert_eq!(
+            python(windows(0xC000_0005)),
+            "Command returned non-zero exit status 3221225477."
+        );
+
+        let not_found = SpawnError::new(SpawnErrorKind::NotFound, Some(2));
+        assert_eq!(
+            python(ProcResult::NotStarted(not_found)),
+            "[Errno 2] program not found"
+        );
+        assert_eq!(
+            python(ProcResult::TimedOut(Some(Duration::from_secs(5)))),
+            "Command timed out after 5 seconds"
+        );
+    }
+
+    #[test]
+    fn test_std() {
+        let std = |result| format(result, ResultFormat::Std);
+        assert_eq!(std(unix(0x0100)), "exit status: 1");
+        assert_eq!(std(unix(9)), "signal: 9 (SIGKILL)");
+        assert_eq!(std(unix(0x8B)), "signal: 11 (SIGSEGV) (core dumped)");
+        assert_eq!(
+            std(unix(0x137F)),
+            "stopped (not terminated) by signal: 19 (SIGSTOP)"
+        );
+        assert_eq!(std(unix(0xFFFF)), "continued (WIFCONTINUED)");
+        assert_eq!(std(windows(1)), "exit code: 1");
+        assert_eq!(std(windows(0xC000_0005)), "exit code: 0xc0000005");
+    }
+
+    #[test]
+    fn test_canonical() {
+        let canonical = |result| format(result, ResultFormat::Canonical);
+        assert_eq!(canonical(unix(0x0100)), "unix:exit:1");
+        assert_eq!(canonical(unix(9)), "unix:signal:SIGKILL");
+        assert_eq!(canonical(unix(0x8B)), "unix:signal:SIGSEGV:core");
+        assert_eq!(canonical(unix(0x40)), "unix:signal:64");
+        assert_eq!(canonical(unix(0x147F)), "unix:stopped:SIGTSTP");
+        assert_eq!(canonical(unix(0xFFFF)), "unix:continued");
+        assert_eq!(canonical(unix(0x1_0100)), "unix:raw:65792");
+        assert_eq!(canonical(windows(0xC000_0005)), "windows:0xC0000005");
+        assert_eq!(
+            canonical(ProcResult::NotStarted(SpawnError::new(
+                SpawnErrorKind::NotFound,
+                Some(2)
+            ))),
+            "not_started:not_found:2"
+        );
+        assert_eq!(
+            canonical(ProcResult::TimedOut(Some(Duration::from_millis(1500)))),
+            "timed_out:1.5s"
+        );
+        assert_eq!(canonical(ProcResult::TimedOut(None)), "timed_out");
+        assert_eq!(format!("{:#}", unix(0x0100)), "unix:exit:1");
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let results = [
+            unix(0),
+            unix(0xFF00),
+            unix(9),
+            unix(0x8B),
+            unix(0x40),
+            unix(0x147F),
+            unix(0xFFFF),
+            unix(0x1_0100),
+            unix(-1),
+            windows(0),
+            windows(0xC000_0005),
+            ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::ExecFormat, None)),
+            ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::Other, Some(-3))),
+            ProcResult::TimedOut(None),
+            ProcResult::TimedOut(Some(Duration::from_secs(5))),
+            ProcResult::TimedOut(Some(Duration::new(1, 7))),
+        ];
+        for result in results {
+            assert_eq!(format!("{result:#}").parse::<ProcResult>(), Ok(result));
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |s: &str| s.parse::<ProcResult>();
+        assert_eq!(parse("unix:signal:9"), Ok(unix(9)));
+        assert_eq!(parse("unix:signal:sigsegv:core"), Ok(unix(0x8B)));
+        assert_eq!(
+            parse("windows:STATUS_ACCESS_VIOLATION"),
+            Ok(windows(0xC000_0005))
+        );
+        assert_eq!(parse("windows:1"), Ok(windows(1)));
+        assert_eq!(
+            parse("timed_out:0.25s"),
+            Ok(ProcResult::TimedOut(Some(Duration::from_millis(250))))
+        );
+
+        let error = |s: &str| parse(s).unwrap_err().to_string();
+        assert_eq!(
+            error("unix:exit:256"),
+            "invalid number found in process result"
+        );
+        assert_eq!(
+            error("unix:signal:SIGFOO"),
+            "unknown name found in process result"
+        );
+        assert_eq!(error("unix:signal:9:dump"), "unrecognized process result");
+        for s in [
+            "unix:signal:0",
+            "unix:signal:127",
+            "unix:signal:200",
+            "unix:stopped:0",
+        ] {
+            assert_eq!(error(s), "invalid signal found in process result");
+        }
+        assert_eq!(parse("unix:signal:126"), Ok(unix(0x7E)));
+        assert_eq!(parse("unix:stopped:255"), Ok(unix(0xFF7F)));
+        assert_eq!(
+            error("timed_out:5"),
+            "invalid number found in process result"
+        );
+        assert_eq!(error("macos:exit:1"), "unrecognized process result");
+        assert_eq!(error(""), "unrecognized process result");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_std_matches_exit_status() {
+        use std::os::unix::process::ExitStatusExt;
+
+        for raw in [0x0000, 0x0100, 0xFF00, 0x0009, 0x008B, 0x137F, 0xFFFF] {
+            let status = std::process::ExitStatus::from_raw(raw);
+            assert_eq!(format(unix(raw), ResultFormat::Std), status.to_string());
+        }
+    }
+}
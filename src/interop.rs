@@ -0,0 +1,148 @@
+//! Translations between Unix wait statuses and Windows exit codes across POSIX layers.
+//!
+//! Unix tools run on Windows through a POSIX layer (Cygwin, MSYS2, or WSL), which reports the
+//! Unix wait status of a tool to a native Windows parent as a Windows exit code. A [`Layer`]
+//! decodes such an exit code back into the [`WaitStatus`] it represents, and vice versa:
+//!
+//! ```rust
+//! use proc_result::ProcResult;
+//! use proc_result::interop::Layer;
+//! use proc_result::unix::Signal;
+//! use proc_result::windows::ExitCode;
+//!
+//! // `bash.exe -c 'kill -9 $$'`, as seen by a native Windows parent.
+//! let result = ProcResult::Windows(ExitCode::from_raw(0x0900));
+//! let status = result.as_unix(Layer::Cygwin).unwrap();
+//! assert_eq!(status.signal(), Some(Signal::KILL));
+//! ```
+
+use crate::equivalence;
+use crate::unix::{ExitCode as UnixExitCode, WaitState, WaitStatus};
+use crate::windows::ExitCode;
+
+/// A POSIX layer that runs Unix programs on Windows.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Layer {
+    /// Cygwin, and runtimes derived from it, such as MSYS2 and Git for Windows.
+    ///
+    /// When a Cygwin process exits to a native Windows parent, the two low bytes of its wait
+    /// status are swapped: an exit code `N` is reported as `N`, and a termination by signal `S`
+    /// as `S << 8` (with `0x80 << 8` set if a core was dumped). A process that crashes with a
+    /// Windows exception Cygwin does not handle reports the exception code itself, which is
+    /// decoded using [`equivalence`].
+    Cygwin,
+
+    /// The Windows Subsystem for Linux (`wsl.exe`).
+    ///
+    /// `wsl.exe` reports the exit code of the Linux command as its own. Commands are run by the
+    /// user's login shell, which reports a termination by signal `S` as exit code `128 + S`.
+    Wsl,
+}
+
+impl Layer {
+    /// Decodes an exit code reported by the layer into the wait status it represents.
+    ///
+    /// Returns `None` if the exit code does not follow the layer's conventions, i.e. because
+    /// the layer itself failed to start.
+    #[must_use]
+    pub fn decode(&self, code: ExitCode) -> Option<WaitStatus> {
+        let raw = code.to_raw();
+        match self {
+            Self::Cygwin if raw <= 0xFFFF => {
+                let status = WaitStatus::from_raw(swap_low_bytes(raw.cast_signed()));
+                match status.state() {
+                    WaitState::Exited { .. } | WaitState::Signaled { .. } => Some(status),
+                    _ => None,
+                }
+            }
+            Self::Cygwin => equivalence::for_windows(code).map(|e| e.unix_status()),
+            Self::Wsl => u8::try_from(raw).ok().map(|code| {
+                WaitStatus::from_raw(
+                    WaitState::Exited {
+                        exit_code: UnixExitCode::from_raw(code),
+                    }
+                    .to_raw(),
+                )
+            }),
+        }
+    }
+
+    /// Encodes a wait status as the exit code the layer reports to a native Windows parent.
+    ///
+    /// Returns `None` if the wait status is not a termination.
+    #[must_use]
+    pub const fn encode(&self, status: WaitStatus) -> Option<ExitCode> {
+        match (self, status.state()) {
+            (Self::Cygwin, WaitState::Exited { .. } | WaitState::Signaled { .. }) => Some(
+                ExitCode::from_raw(swap_low_bytes(status.to_raw()).cast_unsigned()),
+            ),
+            (Self::Wsl, WaitState::Exited { exit_code }) => {
+                Some(ExitCode::from_raw(exit_code.to_raw() as u32))
+            }
+            (Self::Wsl, WaitState::Signaled { signal, .. }) => {
+                Some(ExitCode::from_raw(128 + signal.to_raw() as u32))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Swaps the two low bytes of a value, discarding the rest.
+const fn swap_low_bytes(value: i32) -> i32 {
+    ((value & 0xFF) << 8) | ((value >> 8) & 0xFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unix::Signal;
+
+    #[test]
+    fn test_cygwin_decode_exited() {
+        let status = Layer::Cygwin.decode(ExitCode::from_raw(1)).unwrap();
+        assert_eq!(status.exit_code(), Some(UnixExitCode::GENERAL_ERROR));
+    }
+
+    #[test]
+    fn test_cygwin_decode_signaled() {
+        let status = Layer::Cygwin.decode(ExitCode::from_raw(0x8B00)).unwrap();
+        assert_eq!(
+            status.state(),
+            WaitState::Signaled {
+                signal: Signal::SEGMENTATION_VIOLATION,
+                core_dump: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_cygwin_decode_exception() {
+        let status = Layer::Cygwin.decode(ExitCode::ACCESS_VIOLATION).unwrap();
+        assert_eq!(status.signal(), Some(Signal::SEGMENTATION_VIOLATION));
+        assert_eq!(Layer::Cygwin.decode(ExitCode::from_raw(u32::MAX)), None);
+    }
+
+    #[test]
+    fn test_cygwin_round_trip() {
+        for raw in [0x0000, 0x0100, 0xFF00, 0x0009, 0x008B] {
+            let status = WaitStatus::from_raw(raw);
+            let code = Layer::Cygwin.encode(status).unwrap();
+            assert_eq!(Layer::Cygwin.decode(code), Some(status));
+        }
+    }
+
+    #[test]
+    fn test_wsl() {
+        let status = Layer::Wsl.decode(ExitCode::from_raw(137)).unwrap();
+        assert_eq!(status.exit_code(), Some(UnixExitCode::from_raw(137)));
+        assert_eq!(Layer::Wsl.decode(ExitCode::from_raw(u32::MAX)), None);
+
+        let killed = WaitStatus::from_raw(9);
+        assert_eq!(Layer::Wsl.encode(killed), Some(ExitCode::from_raw(137)));
+        assert_eq!(
+            Layer::Wsl.encode(WaitStatus::from_raw(0x0200)),
+            Some(ExitCode::from_raw(2))
+        );
+    }
+}
@@ -5,18 +5,48 @@
 //! example, on Windows, it can read and interpret exit codes that may have been
 //! recorded from a Linux process, or vice versa.
 //!
-//! [^1]: The `std` feature is enabled by default, but can be disabled.
-#![cfg_attr(not(feature = "std"), no_std)]
+//! [^1]: The `std` feature is enabled by default, but can be disabled. Without it, the `alloc`
+//! feature enables the parts of the crate that allocate, i.e. [`expected`]. On Unix, `std`
+//! depends on `libc`, to end the current process with a signal in [`ProcResult::exit_like`].
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use core::fmt::Display;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-use raw::RawExitCode;
+use core::fmt::Display;
 
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod assert;
+#[cfg(all(feature = "std", any(unix, windows)))]
+pub mod command;
+pub mod equivalence;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(all(feature = "std", any(unix, windows)))]
+mod exit;
+#[cfg(feature = "alloc")]
+pub mod expected;
+pub mod explain;
+mod format;
+pub use format::{FormattedResult, ParseResultError, ResultFormat};
+pub mod interop;
+mod outcome;
+pub use outcome::{CrashKind, Outcome, Severity};
+#[cfg(feature = "alloc")]
+pub mod pipeline;
 pub mod raw;
+#[cfg(feature = "serde")]
+pub mod serialize;
+mod spawn;
+pub use spawn::{SpawnError, SpawnErrorKind};
+#[cfg(feature = "alloc")]
+pub mod summary;
 pub mod unix;
 pub mod windows;
 
 // Import README.md so that doc tests run on it.
+#[cfg(feature = "std")]
 #[allow(dead_code)]
 mod doc_tests {
     #[doc = include_str!("../README.md")]
@@ -24,6 +54,9 @@ mod doc_tests {
 }
 
 /// An exit code or exit state returned by a program.
+///
+/// With the `serde` feature, results are serialized in a tagged, human-readable form, i.e.
+/// `{"platform":"unix","exited":1}`; see the [`serialize`] module for the format.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ProcResult {
@@ -32,6 +65,14 @@ pub enum ProcResult {
 
     /// An unclassified exit status on a Windows platform.
     Windows(windows::ExitCode),
+
+    /// The program could not be started.
+    NotStarted(SpawnError),
+
+    /// The program was terminated by its supervisor after running for too long.
+    ///
+    /// Holds the time limit that was exceeded, if known.
+    TimedOut(Option<core::time::Duration>),
 }
 
 impl ProcResult {
@@ -63,6 +104,111 @@ impl ProcResult {
         Self::Windows(windows::ExitCode::from_raw(1)) // Non-zero exit code
     }
 
+    /// Creates a new `ProcResult` from a Windows exit code, rejecting `STILL_ACTIVE`.
+    ///
+    /// Use this instead of [`ProcResult::Windows`] for exit codes read from `GetExitCodeProcess`
+    /// or recorded from it, where `259` may mean the process was still running.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`windows::StillActive`] if the exit code is [`windows::ExitCode::STILL_ACTIVE`].
+    pub const fn try_from_windows(code: windows::ExitCode) -> Result<Self, windows::StillActive> {
+        if code.is_still_active() {
+            Err(windows::StillActive::new(windows::ProcessState::Ambiguous))
+        } else {
+            Ok(Self::Windows(code))
+        }
+    }
+
+    /// Returns whether the result cannot be told apart from a process that is still running.
+    ///
+    /// This is the case for [`windows::ExitCode::STILL_ACTIVE`]; see [`windows::ProcessState`].
+    #[must_use]
+    pub const fn is_ambiguous(&self) -> bool {
+        match self {
+            ProcResult::Windows(code) => code.is_still_active(),
+            _ => false,
+        }
+    }
+
+    /// Returns the Unix wait status this result represents, if any.
+    ///
+    /// Windows results are decoded according to the conventions of the given POSIX `layer`, i.e.
+    /// to read the exit code of a `bash.exe` run through MSYS2 as the Unix status it really is.
+    #[must_use]
+    pub fn as_unix(&self, layer: interop::Layer) -> Option<unix::WaitStatus> {
+        match self {
+            ProcResult::Unix(status) => Some(*status),
+            ProcResult::Windows(code) => layer.decode(*code),
+            _ => None,
+        }
+    }
+
+    /// Returns a platform-neutral classification of how the process ended.
+    #[must_use]
+    pub const fn outcome(&self) -> Outcome {
+        match self {
+            ProcResult::Unix(status) => Outcome::from_unix(status.state()),
+            ProcResult::Windows(code) => Outcome::from_windows(*code),
+            ProcResult::NotStarted(_) => Outcome::NotStarted,
+            ProcResult::TimedOut(_) => Outcome::TimedOut,
+        }
+    }
+
+    /// Returns how severe the result is.
+    ///
+    /// See [`Severity`] for the ordering.
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        self.outcome().severity()
+    }
+
+    /// Compares two results by [`Severity`], i.e. to find the worst of many with
+    /// [`Iterator::max_by`].
+    ///
+    /// Results of the same severity compare as equal, even if they are different.
+    #[must_use]
+    pub fn cmp_severity(&self, other: &Self) -> core::cmp::Ordering {
+        self.severity().cmp(&other.severity())
+    }
+
+    /// Returns a diagnostic explanation of the result, if one is known.
+    ///
+    /// See [`explain`] for details.
+    #[must_use]
+    pub fn explain(&self) -> Option<explain::Explanation> {
+        match self {
+            ProcResult::Unix(status) => status.explain(),
+            ProcResult::Windows(code) => code.explain(),
+            ProcResult::NotStarted(error) => match error.kind() {
+                SpawnErrorKind::NotFound => Some(explain::COMMAND_NOT_FOUND),
+                SpawnErrorKind::PermissionDenied | SpawnErrorKind::ExecFormat => {
+                    Some(explain::COMMAND_CANNOT_EXECUTE)
+                }
+                SpawnErrorKind::Other => None,
+            },
+            ProcResult::TimedOut(_) => Some(explain::TIMED_OUT),
+        }
+    }
+
+    /// Returns a value that displays the result in the given style.
+    ///
+    /// ```rust
+    /// use proc_result::{ProcResult, ResultFormat};
+    /// use proc_result::unix::WaitStatus;
+    ///
+    /// let result = ProcResult::Unix(WaitStatus::from_raw(0x8B));
+    /// assert_eq!(result.to_string(), "terminated by SIGSEGV (core dumped)");
+    /// assert_eq!(
+    ///     result.format(ResultFormat::Bash).to_string(),
+    ///     "Segmentation fault (core dumped)"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn format(&self, format: ResultFormat) -> FormattedResult {
+        FormattedResult::new(*self, format)
+    }
+
     /// Returns a result that is `Ok` if the exit code or status indicates a success.
     ///
     /// # Errors
@@ -76,12 +222,32 @@ impl ProcResult {
         }
     }
 
+    /// Returns a result that is `Ok` if the result is expected.
+    ///
+    /// See [`expected`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`expected::UnexpectedOutcome`] if the result does not match `expected`.
+    #[cfg(feature = "alloc")]
+    pub fn ok_if(
+        &self,
+        expected: &expected::ExpectedOutcome,
+    ) -> Result<(), expected::UnexpectedOutcome> {
+        if expected.matches(self) {
+            Ok(())
+        } else {
+            Err(expected::UnexpectedOutcome::new(*self, expected.clone()))
+        }
+    }
+
     /// Returns whether the process terminated successfully.
     #[must_use]
     pub fn is_success(&self) -> bool {
         match self {
             ProcResult::Unix(status) => status.exit_code().is_some_and(|code| code.is_success()),
             ProcResult::Windows(code) => code.is_success(),
+            ProcResult::NotStarted(_) | ProcResult::TimedOut(_) => false,
         }
     }
 
@@ -90,20 +256,82 @@ impl ProcResult {
     pub fn is_failure(&self) -> bool {
         !self.is_success()
     }
+
+    /// Ends the current process the way the process this is the result of ended.
+    ///
+    /// This is intended for wrappers, i.e. `env` or `nice`, so that their parent sees the result
+    /// of the wrapped process rather than of the wrapper:
+    ///
+    /// - On Unix, a process that was terminated by a signal has it re-raised, with its handler
+    ///   reset to the default action, so that a shell reports `Killed` (and a core dump occurs
+    ///   if enabled). If the current process survives, i.e. because the signal is blocked, it
+    ///   exits with `128 + signal` instead. An exit code is used as is.
+    /// - On Windows, an exit code is used as is.
+    ///
+    /// Results from the other platform are translated through [`equivalence`], and otherwise
+    /// exit with the code a shell would report, i.e. `128 + signal` for a Unix signal on Windows.
+    /// A Windows exit code that does not fit in a Unix exit code exits with `1`.
+    ///
+    /// A process that could not be started exits with `127` if it was not found, or `126`
+    /// otherwise, as in a shell; one that timed out exits with `124`, as in `timeout(1)`.
+    ///
+    /// ```rust,no_run
+    /// use std::process::Command;
+    /// use proc_result::ProcResult;
+    ///
+    /// let status = Command::new("make").status().unwrap();
+    /// ProcResult::from(status).exit_like();
+    /// ```
+    #[cfg(all(feature = "std", any(unix, windows)))]
+    pub fn exit_like(self) -> ! {
+        exit::exit_like(&self)
+    }
 }
 
-#[cfg(feature = "std")]
+/// Describes what happened to the process, i.e. `killed by signal SIGKILL (9)`.
+///
+/// See [`ProcResult::format`] for other styles.
 impl Display for ProcResult {
+    /// Describes the result, i.e. `exited with code 1`.
+    ///
+    /// The alternate form (`{:#}`) writes the result in [`ResultFormat::Canonical`] instead, i.e.
+    /// `unix:exit:1`, which can be parsed back with [`FromStr`](core::str::FromStr).
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        match self {
-            Self::Unix(status) => write!(f, "Unix exit status: {}", status.to_raw()),
-            Self::Windows(code) => write!(f, "Windows exit code: {}", code.to_raw()),
+        if f.alternate() {
+            self.format(ResultFormat::Canonical).fmt(f)
+        } else {
+            self.format(ResultFormat::Descriptive).fmt(f)
         }
     }
 }
 
 impl core::error::Error for ProcResult {}
 
+/// Keeps the Win32 exit code, i.e. [`windows::ExitCode::SERVICE_SPECIFIC_ERROR`], as a
+/// service-specific exit code is not a Win32 error code, and would be described as one.
+impl From<windows::ServiceExitCode> for ProcResult {
+    fn from(code: windows::ServiceExitCode) -> Self {
+        Self::Windows(code.win32_exit_code())
+    }
+}
+
+impl From<SpawnError> for ProcResult {
+    fn from(error: SpawnError) -> Self {
+        Self::NotStarted(error)
+    }
+}
+
+impl TryFrom<windows::ProcessState> for ProcResult {
+    type Error = windows::StillActive;
+
+    fn try_from(state: windows::ProcessState) -> Result<Self, Self::Error> {
+        match state {
+            windows::ProcessState::Terminated { exit_code } => Ok(Self::Windows(exit_code)),
+            _ => Err(windows::StillActive::new(state)),
+        }
+    }
+}
+
 #[cfg(all(feature = "std", unix))]
 impl From<std::process::ExitStatus> for ProcResult {
     #[allow(unreachable_code)]
@@ -139,4 +367,147 @@ mod tests {
         let result = ProcResult::default_failure();
         assert!(result.is_failure());
     }
+
+    #[test]
+    fn test_try_from_windows_still_active() {
+        use super::{ProcResult, windows};
+
+        let result = ProcResult::try_from_windows(windows::ExitCode::STILL_ACTIVE);
+        assert_eq!(
+            result.unwrap_err().state(),
+            windows::ProcessState::Ambiguous
+        );
+        assert!(ProcResult::Windows(windows::ExitCode::STILL_ACTIVE).is_ambiguous());
+
+        let result = ProcResult::try_from_windows(windows::ExitCode::GENERAL_ERROR);
+        assert_eq!(
+            result,
+            Ok(ProcResult::Windows(windows::ExitCode::GENERAL_ERROR))
+        );
+    }
+
+    #[test]
+    fn test_try_from_process_state() {
+        use super::{ProcResult, windows};
+
+        let state =
+            windows::ProcessState::from_exit_code_and_wait(windows::ExitCode::STILL_ACTIVE, true);
+        assert_eq!(
+            ProcResult::try_from(state),
+            Ok(ProcResult::Windows(windows::ExitCode::STILL_ACTIVE))
+        );
+        assert!(ProcResult::try_from(windows::ProcessState::Running).is_err());
+    }
+
+    #[test]
+    fn test_outcome() {
+        use super::{CrashKind, Outcome, ProcResult, unix, windows};
+
+        let result = ProcResult::Unix(unix::WaitStatus::from_raw(0x0100));
+        assert_eq!(result.outcome(), Outcome::Failed { code: 1 });
+
+        let result = ProcResult::Windows(windows::ExitCode::STACK_OVERFLOW);
+        assert_eq!(
+            result.outcome(),
+            Outcome::Crashed {
+                kind: CrashKind::StackOverflow,
+                core_dumped: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_not_started_and_timed_out() {
+        use super::{Outcome, ProcResult, SpawnError, SpawnErrorKind};
+
+        let result = ProcResult::from(SpawnError::new(SpawnErrorKind::NotFound, None));
+        assert!(result.is_failure());
+        assert_eq!(result.ok(), Err(result));
+        assert_eq!(result.outcome(), Outcome::NotStarted);
+
+        let result = ProcResult::TimedOut(Some(core::time::Duration::from_secs(5)));
+        assert!(result.is_failure());
+        assert_eq!(result.outcome(), Outcome::TimedOut);
+    }
+
+    #[test]
+    fn test_explain() {
+        use super::{ProcResult, SpawnError, SpawnErrorKind, explain, unix, windows};
+
+        let result = ProcResult::Unix(unix::WaitStatus::from_raw(13));
+        assert_eq!(result.explain(), unix::Signal::BROKEN_PIPE.explain());
+
+        let result = ProcResult::Windows(windows::ExitCode::COMMAND_NOT_RECOGNIZED);
+        assert_eq!(result.explain(), Some(explain::COMMAND_NOT_FOUND));
+
+        let result = ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::ExecFormat, None));
+        assert_eq!(result.explain(), Some(explain::COMMAND_CANNOT_EXECUTE));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_display_not_started_and_timed_out() {
+        use super::{ProcResult, SpawnError, SpawnErrorKind};
+
+        let result = ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::NotFound, Some(2)));
+        assert_eq!(
+            result.to_string(),
+            "failed to start: program not found (os error 2)"
+        );
+
+        let result = ProcResult::TimedOut(Some(core::time::Duration::from_secs(5)));
+        assert_eq!(result.to_string(), "timed out after 5s");
+        assert_eq!(ProcResult::TimedOut(None).to_string(), "timed out");
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::{ProcResult, SpawnError, SpawnErrorKind, windows};
+    use serde_test::{Configure, Token, assert_tokens};
+
+    #[test]
+    fn test_serde_not_started() {
+        let result = ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::NotFound, None));
+        let json = serde_json::to_string(&result).unwrap();
+        assert_eq!(
+            json,
+            r#"{"version":1,"not_started":{"kind":"NotFound","raw_os_error":null}}"#
+        );
+        assert_eq!(serde_json::from_str::<ProcResult>(&json).unwrap(), result);
+    }
+
+    #[test]
+    fn test_serde_windows() {
+        assert_tokens(
+            &ProcResult::Windows(windows::ExitCode::GENERAL_ERROR).readable(),
+            &[
+                Token::Map { len: Some(3) },
+                Token::Str("version"),
+                Token::U64(1),
+                Token::Str("platform"),
+                Token::UnitVariant {
+                    name: "Platform",
+                    variant: "windows",
+                },
+                Token::Str("code"),
+                Token::U32(1),
+                Token::MapEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_serde_windows_compact() {
+        assert_tokens(
+            &ProcResult::Windows(windows::ExitCode::GENERAL_ERROR).compact(),
+            &[
+                Token::NewtypeVariant {
+                    name: "ProcResult",
+                    variant: "Windows",
+                },
+                Token::U32(1),
+            ],
+        );
+    }
 }
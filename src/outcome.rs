@@ -0,0 +1,388 @@
+use core::fmt::{self, Display};
+
+use crate::unix::{Signal, WaitState};
+use crate::windows::{ExitCode, SoftwareExceptionKind};
+
+/// A platform-neutral classification of how a process ended.
+///
+/// Created by [`ProcResult::outcome`](crate::ProcResult::outcome), and intended for code that
+/// renders or reacts to results without knowing which platform produced them:
+///
+/// ```rust
+/// use proc_result::{CrashKind, Outcome, ProcResult};
+/// use proc_result::{unix, windows};
+///
+/// let unix = ProcResult::Unix(unix::WaitStatus::from_raw(0x8B));
+/// let windows = ProcResult::Windows(windows::ExitCode::ACCESS_VIOLATION);
+///
+/// assert_eq!(
+///     unix.outcome(),
+///     Outcome::Crashed { kind: CrashKind::MemoryAccess, core_dumped: true }
+/// );
+/// assert_eq!(
+///     windows.outcome(),
+///     Outcome::Crashed { kind: CrashKind::MemoryAccess, core_dumped: false }
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Outcome {
+    /// The process exited successfully.
+    Success,
+
+    /// The process exited with a non-zero exit code.
+    Failed {
+        /// The exit code of the process.
+        code: u32,
+    },
+
+    /// The process was interrupted by the user, i.e. with `Ctrl+C`.
+    Interrupted,
+
+    /// The process was asked to terminate, i.e. by `SIGTERM`, and did.
+    Terminated,
+
+    /// The process was forcibly killed, i.e. by `SIGKILL`.
+    Killed,
+
+    /// The process crashed.
+    Crashed {
+        /// What kind of crash occurred.
+        kind: CrashKind,
+
+        /// Whether a core dump occurred.
+        core_dumped: bool,
+    },
+
+    /// The process was stopped, and may be continued later.
+    Stopped,
+
+    /// The process could not be started.
+    NotStarted,
+
+    /// The process was terminated by its supervisor after running for too long.
+    TimedOut,
+
+    /// The result could not be classified.
+    Unknown,
+}
+
+impl Outcome {
+    /// Classifies a Unix wait state.
+    #[must_use]
+    pub const fn from_unix(state: WaitState) -> Self {
+        match state {
+            WaitState::Exited { exit_code } if exit_code.is_success() => Self::Success,
+            WaitState::Exited { exit_code } => Self::Failed {
+                code: exit_code.to_raw() as u32,
+            },
+            WaitState::Signaled { signal, core_dump } => match CrashKind::from_signal(signal) {
+                Some(kind) => Self::Crashed {
+                    kind,
+                    core_dumped: core_dump,
+                },
+                None => match signal {
+                    Signal::INTERRUPT => Self::Interrupted,
+                    Signal::KILL => Self::Killed,
+                    _ => Self::Terminated,
+                },
+            },
+            WaitState::Stopped { .. } => Self::Stopped,
+            WaitState::Continued | WaitState::Unsupported(_) => Self::Unknown,
+        }
+    }
+
+    /// Classifies a Windows exit code.
+    ///
+    /// Exception codes in the `NTSTATUS` error range are crashes, and
+    /// [`ExitCode::STILL_ACTIVE`] is [`Outcome::Unknown`], as the process may still be running.
+    #[must_use]
+    pub const fn from_windows(code: ExitCode) -> Self {
+        if code.is_success() {
+            return Self::Success;
+        }
+        if code.is_still_active() {
+            return Self::Unknown;
+        }
+        if code.to_raw() == ExitCode::TERMINATED_BY_CTRL_C.to_raw() {
+            return Self::Interrupted;
+        }
+        if code.to_raw() == DBG_TERMINATE_PROCESS {
+            return Self::Killed;
+        }
+        match CrashKind::from_windows(code) {
+            Some(kind) => Self::Crashed {
+                kind,
+                core_dumped: false,
+            },
+            None => Self::Failed {
+                code: code.to_raw(),
+            },
+        }
+    }
+
+    /// Returns `true` if the process exited successfully.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Success)
+    }
+
+    /// Returns how severe the outcome is.
+    ///
+    /// Processes that could not be started, and results that could not be classified, are
+    /// failures. Stopped processes were stopped by a signal, and are [`Severity::Signaled`].
+    #[must_use]
+    pub const fn severity(&self) -> Severity {
+        match self {
+            Self::Success => Severity::Success,
+            Self::Failed { .. } | Self::NotStarted | Self::Unknown => Severity::Failure,
+            Self::TimedOut => Severity::TimedOut,
+            Self::Crashed {
+                core_dumped: true, ..
+            } => Severity::CoreDumped,
+            Self::Interrupted
+            | Self::Terminated
+            | Self::Killed
+            | Self::Crashed { .. }
+            | Self::Stopped => Severity::Signaled,
+        }
+    }
+}
+
+/// How severe the way a process ended is, from least to most severe.
+///
+/// Created by [`ProcResult::severity`](crate::ProcResult::severity), and ordered so that the
+/// worst of many results can be found:
+///
+/// ```rust
+/// use proc_result::{ProcResult, Severity};
+/// use proc_result::unix::WaitStatus;
+///
+/// let results = [0x0100, 0x8B, 9].map(|raw| ProcResult::Unix(WaitStatus::from_raw(raw)));
+/// let worst = results.iter().max_by(|a, b| a.cmp_severity(b));
+///
+/// assert_eq!(worst, Some(&ProcResult::Unix(WaitStatus::from_raw(0x8B))));
+/// assert_eq!(worst.map(ProcResult::severity), Some(Severity::CoreDumped));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum Severity {
+    /// The process exited successfully.
+    Success,
+
+    /// The process failed, i.e. exited with a non-zero exit code, or could not be started.
+    Failure,
+
+    /// The process was terminated by its supervisor after running for too long.
+    TimedOut,
+
+    /// The process was terminated or stopped by a signal, or crashed without a core dump.
+    Signaled,
+
+    /// The process crashed, and a core dump occurred.
+    CoreDumped,
+}
+
+impl Severity {
+    /// Every severity, from least to most severe.
+    pub const ALL: [Self; 5] = [
+        Self::Success,
+        Self::Failure,
+        Self::TimedOut,
+        Self::Signaled,
+        Self::CoreDumped,
+    ];
+
+    /// Returns a short description of the severity, i.e. `timed out`.
+    #[must_use]
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+            Self::TimedOut => "timed out",
+            Self::Signaled => "signaled",
+            Self::CoreDumped => "core dumped",
+        }
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.description())
+    }
+}
+
+/// The exit code of a process terminated by a debugger (`DBG_TERMINATE_PROCESS`).
+const DBG_TERMINATE_PROCESS: u32 = 0x4001_0004;
+
+/// What kind of crash terminated a process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CrashKind {
+    /// An invalid memory access (`SIGSEGV`, `STATUS_ACCESS_VIOLATION`).
+    MemoryAccess,
+
+    /// A bus error, such as a misaligned access (`SIGBUS`, `STATUS_IN_PAGE_ERROR`).
+    BusError,
+
+    /// A stack overflow (`STATUS_STACK_OVERFLOW`).
+    ///
+    /// Unix reports stack overflows as [`CrashKind::MemoryAccess`].
+    StackOverflow,
+
+    /// An invalid instruction (`SIGILL`, `STATUS_ILLEGAL_INSTRUCTION`).
+    IllegalInstruction,
+
+    /// An arithmetic error, such as division by zero (`SIGFPE`,
+    /// `STATUS_INTEGER_DIVIDE_BY_ZERO`).
+    Arithmetic,
+
+    /// A deliberate abort (`SIGABRT`, `STATUS_STACK_BUFFER_OVERRUN`).
+    Abort,
+
+    /// A breakpoint with no debugger attached (`SIGTRAP`, `STATUS_BREAKPOINT`).
+    Breakpoint,
+
+    /// An exception of a language runtime that was not caught, i.e. a C++ or .NET exception.
+    UnhandledException,
+
+    /// Any other crash.
+    Other,
+}
+
+impl CrashKind {
+    /// Returns the kind of crash a signal indicates, or `None` if it does not indicate a crash.
+    #[must_use]
+    pub const fn from_signal(signal: Signal) -> Option<Self> {
+        Some(match signal {
+            Signal::ILLEGAL_INSTRUCTION => Self::IllegalInstruction,
+            Signal::TRAP => Self::Breakpoint,
+            Signal::ABORT => Self::Abort,
+            Signal::BUS_ERROR => Self::BusError,
+            Signal::FLOATING_POINT_EXCEPTION => Self::Arithmetic,
+            Signal::SEGMENTATION_VIOLATION => Self::MemoryAccess,
+            Signal::BAD_SYSTEM_CALL => Self::Other,
+            _ => return None,
+        })
+    }
+
+    /// Returns the kind of crash an exit code indicates, or `None` if it does not indicate one.
+    #[must_use]
+    pub const fn from_windows(code: ExitCode) -> Option<Self> {
+        if let Some(exception) = code.software_exception() {
+            return Some(match exception.kind() {
+                SoftwareExceptionKind::UnhandledException => Self::UnhandledException,
+                SoftwareExceptionKind::FatalError => Self::Abort,
+            });
+        }
+        Some(match code.to_raw() {
+            0xC000_0005 => Self::MemoryAccess,
+            0xC000_0006 | 0x8000_0002 => Self::BusError,
+            0xC000_00FD => Self::StackOverflow,
+            0xC000_001D | 0xC000_0096 => Self::IllegalInstruction,
+            0xC000_008E | 0xC000_0094 | 0xC000_0095 => Self::Arithmetic,
+            0xC000_0409 => Self::Abort,
+            0x8000_0003 => Self::Breakpoint,
+            // Other system-defined (customer bit unset) NTSTATUS errors.
+            raw if raw & 0xE000_0000 == 0xC000_0000 => Self::Other,
+            _ => return None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unix;
+
+    fn unix(raw: i32) -> Outcome {
+        Outcome::from_unix(unix::WaitState::from_raw(raw))
+    }
+
+    fn windows(raw: u32) -> Outcome {
+        Outcome::from_windows(ExitCode::from_raw(raw))
+    }
+
+    #[test]
+    fn test_from_unix_exited() {
+        assert_eq!(unix(0x0000), Outcome::Success);
+        assert_eq!(unix(0x0100), Outcome::Failed { code: 1 });
+    }
+
+    #[test]
+    fn test_from_unix_signaled() {
+        assert_eq!(unix(2), Outcome::Interrupted);
+        assert_eq!(unix(9), Outcome::Killed);
+        assert_eq!(unix(13), Outcome::Terminated);
+        assert_eq!(unix(15), Outcome::Terminated);
+        assert_eq!(
+            unix(0x86),
+            Outcome::Crashed {
+                kind: CrashKind::Abort,
+                core_dumped: true
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_unix_stopped() {
+        assert_eq!(unix(0x137F), Outcome::Stopped);
+        assert_eq!(unix(0xFFFF), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_from_windows() {
+        assert_eq!(windows(0), Outcome::Success);
+        assert_eq!(windows(1), Outcome::Failed { code: 1 });
+        assert_eq!(windows(u32::MAX), Outcome::Failed { code: u32::MAX });
+        assert_eq!(windows(259), Outcome::Unknown);
+        assert_eq!(windows(0xC000_013A), Outcome::Interrupted);
+        assert_eq!(windows(0x4001_0004), Outcome::Killed);
+    }
+
+    #[test]
+    fn test_from_windows_crashed() {
+        let crashed = |kind| Outcome::Crashed {
+            kind,
+            core_dumped: false,
+        };
+        assert_eq!(windows(0xC000_00FD), crashed(CrashKind::StackOverflow));
+        assert_eq!(windows(0xC000_0409), crashed(CrashKind::Abort));
+        assert_eq!(windows(0xE06D_7363), crashed(CrashKind::UnhandledException));
+        assert_eq!(windows(0xC000_0135), crashed(CrashKind::Other));
+    }
+
+    #[test]
+    fn test_crash_kind_from_signal() {
+        assert_eq!(
+            CrashKind::from_signal(Signal::SEGMENTATION_VIOLATION),
+            Some(CrashKind::MemoryAccess)
+        );
+        assert_eq!(
+            CrashKind::from_signal(Signal::BAD_SYSTEM_CALL),
+            Some(CrashKind::Other)
+        );
+        assert_eq!(CrashKind::from_signal(Signal::KILL), None);
+        assert_eq!(CrashKind::from_signal(Signal::TERMINATION), None);
+    }
+
+    #[test]
+    fn test_severity() {
+        assert_eq!(unix(0x0000).severity(), Severity::Success);
+        assert_eq!(unix(0x0100).severity(), Severity::Failure);
+        assert_eq!(Outcome::NotStarted.severity(), Severity::Failure);
+        assert_eq!(Outcome::TimedOut.severity(), Severity::TimedOut);
+        assert_eq!(unix(15).severity(), Severity::Signaled);
+        assert_eq!(unix(0x137F).severity(), Severity::Signaled);
+        assert_eq!(unix(0x0B).severity(), Severity::Signaled);
+        assert_eq!(unix(0x8B).severity(), Severity::CoreDumped);
+        assert_eq!(windows(0xC000_0005).severity(), Severity::Signaled);
+        assert!(Severity::ALL.is_sorted());
+    }
+}
@@ -0,0 +1,211 @@
+//! Combined results of pipelines, i.e. `producer | filter | consumer`.
+//!
+//! A shell reports a single status for a pipeline, chosen from the results of its stages by a
+//! [`PipelineRule`]. A [`PipelineResult`] holds the result of each stage, and computes the
+//! combined result under any rule:
+//!
+//! ```rust
+//! use proc_result::ProcResult;
+//! use proc_result::pipeline::{PipelineResult, PipelineRule};
+//! use proc_result::unix::WaitStatus;
+//!
+//! // `false | true`
+//! let pipeline: PipelineResult = [0x0100, 0]
+//!     .into_iter()
+//!     .map(|raw| ProcResult::Unix(WaitStatus::from_raw(raw)))
+//!     .collect();
+//! assert!(pipeline.is_success(PipelineRule::Last));
+//! assert!(!pipeline.is_success(PipelineRule::Pipefail));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::ProcResult;
+use crate::unix::{ExitCode, Signal};
+
+/// How the combined result of a pipeline is chosen from the results of its stages.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PipelineRule {
+    /// The result of the last stage, which is the default in `bash` and POSIX shells.
+    #[default]
+    Last,
+
+    /// The result of the last (rightmost) stage that failed, or of the last stage if none did.
+    ///
+    /// This is the rule of `set -o pipefail` in `bash`, `zsh` and `ksh`.
+    Pipefail,
+
+    /// The result of the first (leftmost) stage that failed, or of the last stage if none did.
+    ///
+    /// The pipeline succeeds only if every stage succeeds, like [`PipelineRule::Pipefail`], but
+    /// reports the failure that is most likely to have caused the others, i.e. a producer that
+    /// crashed, rather than a consumer that then rejected its truncated input.
+    All,
+}
+
+/// The results of the stages of a pipeline, in order.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PipelineResult {
+    stages: Vec<ProcResult>,
+    ignore_sigpipe: bool,
+}
+
+impl PipelineResult {
+    /// Creates a `PipelineResult` from the results of each stage, in order.
+    #[must_use]
+    pub fn new(stages: impl IntoIterator<Item = ProcResult>) -> Self {
+        Self {
+            stages: stages.into_iter().collect(),
+            ignore_sigpipe: false,
+        }
+    }
+
+    /// Treats stages before the last that were terminated by `SIGPIPE`, or that exited with code
+    /// `141` (`128 + SIGPIPE`, as reported by a shell), as successful.
+    ///
+    /// This happens when a later stage exits before reading all of its input, i.e. `head`, and is
+    /// usually not an error.
+    #[must_use]
+    pub fn ignore_sigpipe(mut self, ignore: bool) -> Self {
+        self.ignore_sigpipe = ignore;
+        self
+    }
+
+    /// Returns the results of each stage, in order.
+    #[must_use]
+    pub fn stages(&self) -> &[ProcResult] {
+        &self.stages
+    }
+
+    /// Returns `true` if the stage at `index` succeeded, taking
+    /// [`PipelineResult::ignore_sigpipe`] into account.
+    ///
+    /// Returns `false` if there is no stage at `index`.
+    #[must_use]
+    pub fn is_stage_success(&self, index: usize) -> bool {
+        let Some(result) = self.stages.get(index) else {
+            return false;
+        };
+        result.is_success()
+            || (self.ignore_sigpipe && index + 1 < self.stages.len() && is_sigpipe(result))
+    }
+
+    /// Returns the indices of the stages that failed, in order.
+    pub fn failures(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.stages.len()).filter(|&index| !self.is_stage_success(index))
+    }
+
+    /// Returns the combined result of the pipeline under `rule`, or `None` if it has no stages.
+    #[must_use]
+    pub fn result(&self, rule: PipelineRule) -> Option<ProcResult> {
+        let index = match rule {
+            PipelineRule::Last => None,
+            PipelineRule::Pipefail => self.failures().last(),
+            PipelineRule::All => self.failures().next(),
+        };
+        match index {
+            Some(index) => Some(self.stages[index]),
+            None => self.stages.last().copied(),
+        }
+    }
+
+    /// Returns `true` if the pipeline succeeded under `rule`.
+    ///
+    /// A pipeline without stages does not succeed.
+    #[must_use]
+    pub fn is_success(&self, rule: PipelineRule) -> bool {
+        match rule {
+            PipelineRule::Last => self
+                .stages
+                .len()
+                .checked_sub(1)
+                .is_some_and(|last| self.is_stage_success(last)),
+            PipelineRule::Pipefail | PipelineRule::All => {
+                !self.stages.is_empty() && self.failures().next().is_none()
+            }
+        }
+    }
+}
+
+impl FromIterator<ProcResult> for PipelineResult {
+    fn from_iter<I: IntoIterator<Item = ProcResult>>(stages: I) -> Self {
+        Self::new(stages)
+    }
+}
+
+/// Returns `true` if the process was terminated by `SIGPIPE`, or a shell reported that it was.
+fn is_sigpipe(result: &ProcResult) -> bool {
+    const SHELL_SIGPIPE: ExitCode = ExitCode::from_raw(128 + Signal::BROKEN_PIPE.to_raw());
+    match result {
+        ProcResult::Unix(status) => {
+            status.signal() == Some(Signal::BROKEN_PIPE)
+                || status.exit_code() == Some(SHELL_SIGPIPE)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unix::WaitStatus;
+
+    fn unix(raw: i32) -> ProcResult {
+        ProcResult::Unix(WaitStatus::from_raw(raw))
+    }
+
+    fn pipeline(raw: &[i32]) -> PipelineResult {
+        raw.iter().map(|raw| unix(*raw)).collect()
+    }
+
+    #[test]
+    fn test_rules() {
+        // `exit 2 | exit 3 | true`
+        let stages = pipeline(&[0x0200, 0x0300, 0]);
+        assert_eq!(stages.result(PipelineRule::Last), Some(unix(0)));
+        assert_eq!(stages.result(PipelineRule::Pipefail), Some(unix(0x0300)));
+        assert_eq!(stages.result(PipelineRule::All), Some(unix(0x0200)));
+        assert!(stages.is_success(PipelineRule::Last));
+        assert!(!stages.is_success(PipelineRule::Pipefail));
+        assert!(!stages.is_success(PipelineRule::All));
+        assert_eq!(stages.failures().collect::<Vec<_>>(), [0, 1]);
+    }
+
+    #[test]
+    fn test_all_succeed() {
+        let stages = pipeline(&[0, 0]);
+        for rule in [
+            PipelineRule::Last,
+            PipelineRule::Pipefail,
+            PipelineRule::All,
+        ] {
+            assert_eq!(stages.result(rule), Some(unix(0)));
+            assert!(stages.is_success(rule));
+        }
+    }
+
+    #[test]
+    fn test_ignore_sigpipe() {
+        // `yes | head -1`, with `yes` killed by `SIGPIPE`, or reported as 141 by a subshell.
+        for upstream in [13, 0x8D00] {
+            let stages = pipeline(&[upstream, 0]);
+            assert!(!stages.is_success(PipelineRule::Pipefail));
+
+            let stages = stages.ignore_sigpipe(true);
+            assert!(stages.is_success(PipelineRule::Pipefail));
+            assert_eq!(stages.result(PipelineRule::Pipefail), Some(unix(0)));
+        }
+
+        // The last stage has no downstream to close its output, so `SIGPIPE` is a failure.
+        let stages = pipeline(&[0, 13]).ignore_sigpipe(true);
+        assert!(!stages.is_success(PipelineRule::Last));
+        assert_eq!(stages.result(PipelineRule::All), Some(unix(13)));
+    }
+
+    #[test]
+    fn test_empty() {
+        let stages = PipelineResult::default();
+        assert_eq!(stages.result(PipelineRule::Last), None);
+        assert!(!stages.is_success(PipelineRule::Pipefail));
+    }
+}
@@ -4,8 +4,8 @@
 //! well as a concrete implementation for Unix and Windows platforms. While useful, the `raw` module
 //! is not necessary for most applications.
 
+use core::fmt::Debug;
 use core::fmt::Display;
-use std::fmt::Debug;
 
 use num_traits::{PrimInt, Zero};
 
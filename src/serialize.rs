@@ -0,0 +1,675 @@
+//! Serde support for [`ProcResult`], in a tagged and human-readable form.
+//!
+//! Results are serialized as maps, tagged by `platform` for results of a process that ran:
+//!
+//! | Result              | Serialized as                                                          |
+//! |---------------------|------------------------------------------------------------------------|
+//! | Exited with `1`     | `{"version":1,"platform":"unix","exited":1}`                           |
+//! | Killed (`SIGKILL`)  | `{"version":1,"platform":"unix","signal":"SIGKILL","core_dump":false}` |
+//! | Stopped (`SIGTSTP`) | `{"version":1,"platform":"unix","stopped":"SIGTSTP"}`                  |
+//! | Continued           | `{"version":1,"platform":"unix","continued":true}`                     |
+//! | Unknown status      | `{"version":1,"platform":"unix","raw":65535}`                          |
+//! | Windows exit code   | `{"version":1,"platform":"windows","code":3221225477}`                 |
+//! | Not started         | `{"version":1,"not_started":{"kind":"NotFound","raw_os_error":2}}`     |
+//! | Timed out after 5s  | `{"version":1,"timed_out":{"secs":5,"nanos":0}}`                       |
+//! | Timed out           | `{"version":1,"timed_out":null}`                                       |
+//!
+//! Signals without a name are serialized as numbers. Records written without a `version`, by
+//! earlier versions of this crate, are read as version 1. When deserializing:
+//!
+//! - `version` must be `1`; an incompatible change to the format will write a new version, so
+//!   that older readers reject it instead of misreading it;
+//! - unknown fields are ignored, so that compatible additions can be read by older versions;
+//! - `{"platform":"unix","raw":256}` is accepted for any wait status;
+//! - signals and Windows exit codes are accepted as numbers or names, and Windows exit codes also
+//!   in any form accepted by [`windows::ExitCode`]'s `FromStr`, i.e. `"0xC0000005"`;
+//! - the raw forms written by earlier versions of this crate are accepted, i.e. `{"Unix":256}`;
+//! - conflicting fields, i.e. both `exited` and `signal`, and duplicate fields are rejected;
+//! - signals that a wait status cannot hold, i.e. `"signal":0`, are rejected instead of being
+//!   read as a different state.
+//!
+//! Formats that are not human-readable, i.e. `bincode`, cannot read maps with named fields, and
+//! use the compact form written by earlier versions of this crate instead: an enum with the raw
+//! wait status or exit code.
+//!
+//! [`windows::ExitCode`]: crate::windows::ExitCode
+
+use core::fmt;
+use core::time::Duration;
+
+use serde::de::{self, Deserializer, IgnoredAny, MapAccess, Unexpected, Visitor};
+use serde::ser::{SerializeMap, Serializer};
+use serde::{Deserialize, Serialize};
+
+use crate::unix::{self, Signal, WaitState, WaitStatus};
+use crate::{ProcResult, SpawnError, windows};
+
+/// The version of the format that is read and written.
+const VERSION: u64 = 1;
+
+impl Serialize for ProcResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if !serializer.is_human_readable() {
+            return Compact::from(*self).serialize(serializer);
+        }
+        match *self {
+            Self::Unix(status) => {
+                let state = status.state();
+                let len = if matches!(state, WaitState::Signaled { .. }) {
+                    4
+                } else {
+                    3
+                };
+                let mut map = serializer.serialize_map(Some(len))?;
+                map.serialize_entry("version", &VERSION)?;
+                map.serialize_entry("platform", &Platform::Unix)?;
+                match state {
+                    WaitState::Exited { exit_code } => map.serialize_entry("exited", &exit_code)?,
+                    WaitState::Signaled { signal, core_dump } => {
+                        map.serialize_entry("signal", &SignalName(signal))?;
+                        map.serialize_entry("core_dump", &core_dump)?;
+                    }
+                    WaitState::Stopped { signal } => {
+                        map.serialize_entry("stopped", &SignalName(signal))?;
+                    }
+                    WaitState::Continued => map.serialize_entry("continued", &true)?,
+                    WaitState::Unsupported(raw) => map.serialize_entry("raw", &raw)?,
+                }
+                map.end()
+            }
+            Self::Windows(code) => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("version", &VERSION)?;
+                map.serialize_entry("platform", &Platform::Windows)?;
+                map.serialize_entry("code", &code)?;
+                map.end()
+            }
+            Self::NotStarted(error) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("version", &VERSION)?;
+                map.serialize_entry("not_started", &error)?;
+                map.end()
+            }
+            Self::TimedOut(limit) => {
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("version", &VERSION)?;
+                map.serialize_entry("timed_out", &limit)?;
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ProcResult {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if !deserializer.is_human_readable() {
+            return Compact::deserialize(deserializer).map(Self::from);
+        }
+        deserializer.deserialize_map(ProcResultVisitor)
+    }
+}
+
+/// The form used by formats that are not human-readable, as derived by earlier versions.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "ProcResult")]
+enum Compact {
+    Unix(WaitStatus),
+    Windows(windows::ExitCode),
+    NotStarted(SpawnError),
+    TimedOut(Option<Duration>),
+}
+
+impl From<ProcResult> for Compact {
+    fn from(result: ProcResult) -> Self {
+        match result {
+            ProcResult::Unix(status) => Self::Unix(status),
+            ProcResult::Windows(code) => Self::Windows(code),
+            ProcResult::NotStarted(error) => Self::NotStarted(error),
+            ProcResult::TimedOut(limit) => Self::TimedOut(limit),
+        }
+    }
+}
+
+impl From<Compact> for ProcResult {
+    fn from(result: Compact) -> Self {
+        match result {
+            Compact::Unix(status) => Self::Unix(status),
+            Compact::Windows(code) => Self::Windows(code),
+            Compact::NotStarted(error) => Self::NotStarted(error),
+            Compact::TimedOut(limit) => Self::TimedOut(limit),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum Platform {
+    Unix,
+    Windows,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum Field {
+    Version,
+    Platform,
+    Exited,
+    Signal,
+    CoreDump,
+    Stopped,
+    Continued,
+    Raw,
+    Code,
+    NotStarted,
+    TimedOut,
+    #[serde(rename = "Unix")]
+    LegacyUnix,
+    #[serde(rename = "Windows")]
+    LegacyWindows,
+    #[serde(rename = "NotStarted")]
+    LegacyNotStarted,
+    #[serde(rename = "TimedOut")]
+    LegacyTimedOut,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The fields of a serialized [`ProcResult`], as they are read.
+#[derive(Default)]
+struct Fields {
+    version: Option<u64>,
+    platform: Option<Platform>,
+    exited: Option<unix::ExitCode>,
+    signal: Option<Signal>,
+    core_dump: Option<bool>,
+    stopped: Option<Signal>,
+    continued: Option<bool>,
+    raw: Option<i32>,
+    code: Option<windows::ExitCode>,
+    /// A result that is not tagged by `platform`, or in a raw form, with the field it was in.
+    other: Option<(&'static str, ProcResult)>,
+}
+
+impl Fields {
+    /// Returns the names of the fields that describe the result, in the order they are checked.
+    fn present(&self) -> impl Iterator<Item = &'static str> {
+        [
+            ("platform", self.platform.is_some()),
+            ("exited", self.exited.is_some()),
+            ("signal", self.signal.is_some()),
+            ("core_dump", self.core_dump.is_some()),
+            ("stopped", self.stopped.is_some()),
+            ("continued", self.continued.is_some()),
+            ("raw", self.raw.is_some()),
+            ("code", self.code.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, present)| present.then_some(name))
+        .chain(self.other.map(|(name, _)| name))
+    }
+
+    fn into_result<E: de::Error>(self) -> Result<ProcResult, E> {
+        if let Some(version) = self.version
+            && version != VERSION
+        {
+            return Err(E::invalid_value(
+                Unexpected::Unsigned(version),
+                &"format version 1",
+            ));
+        }
+
+        // The field that determines the result; every other field must belong to it.
+        let primary = match self.platform {
+            Some(Platform::Unix) => ["exited", "signal", "stopped", "continued", "raw"]
+                .into_iter()
+                .find(|name| self.present().any(|present| present == *name))
+                .ok_or_else(|| E::missing_field("exited"))?,
+            Some(Platform::Windows) => "code",
+            None => match self.other {
+                Some((name, _)) => name,
+                None => return Err(E::missing_field("platform")),
+            },
+        };
+        let belongs = |name: &str| {
+            name == primary
+                || (name == "platform" && self.platform.is_some())
+                || (name == "core_dump" && primary == "signal")
+        };
+        if let Some(conflict) = self.present().find(|name| !belongs(name)) {
+            return Err(E::custom(format_args!(
+                "conflicting fields `{primary}` and `{conflict}`"
+            )));
+        }
+
+        let state = match self.platform {
+            Some(Platform::Unix) => {
+                match (self.exited, self.signal, self.stopped, self.continued) {
+                    (Some(exit_code), ..) => WaitState::Exited { exit_code },
+                    (_, Some(signal), ..) => WaitState::Signaled {
+                        signal,
+                        core_dump: self.core_dump.unwrap_or(false),
+                    },
+                    (_, _, Some(signal), _) => WaitState::Stopped { signal },
+                    (_, _, _, Some(true)) => WaitState::Continued,
+                    (_, _, _, Some(false)) => {
+                        return Err(E::invalid_value(Unexpected::Bool(false), &"true"));
+                    }
+                    _ => WaitState::Unsupported(self.raw.unwrap_or_default()),
+                }
+            }
+            Some(Platform::Windows) => {
+                return self
+                    .code
+                    .map(ProcResult::Windows)
+                    .ok_or_else(|| E::missing_field("code"));
+            }
+            None => {
+                return self
+                    .other
+                    .map(|(_, result)| result)
+                    .ok_or_else(|| E::missing_field("platform"));
+            }
+        };
+        if let WaitState::Signaled { signal, .. } | WaitState::Stopped { signal } = state
+            && !state.is_encodable()
+        {
+            return Err(E::invalid_value(
+                Unexpected::Unsigned(signal.to_raw().into()),
+                &"a signal that a wait status can hold",
+            ));
+        }
+        let raw = match state {
+            WaitState::Unsupported(raw) => raw,
+            state => state.to_raw(),
+        };
+        Ok(ProcResult::Unix(WaitStatus::from_raw(raw)))
+    }
+}
+
+/// Stores a field that was read, unless it was already read.
+fn set<T, E: de::Error>(slot: &mut Option<T>, value: T, name: &'static str) -> Result<(), E> {
+    if slot.is_some() {
+        return Err(E::duplicate_field(name));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+/// Stores a result read from a field that is not tagged by `platform`, unless one was already read.
+fn set_other<E: de::Error>(
+    fields: &mut Fields,
+    name: &'static str,
+    result: ProcResult,
+) -> Result<(), E> {
+    match fields.other {
+        Some((other, _)) if other == name => Err(E::duplicate_field(name)),
+        Some((other, _)) => Err(E::custom(format_args!(
+            "conflicting fields `{other}` and `{name}`"
+        ))),
+        None => {
+            fields.other = Some((name, result));
+            Ok(())
+        }
+    }
+}
+
+struct ProcResultVisitor;
+
+impl<'de> Visitor<'de> for ProcResultVisitor {
+    type Value = ProcResult;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a process result")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut fields = Fields::default();
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version => set(&mut fields.version, map.next_value()?, "version")?,
+                Field::Platform => set(&mut fields.platform, map.next_value()?, "platform")?,
+                Field::Exited => set(&mut fields.exited, map.next_value()?, "exited")?,
+                Field::Signal => {
+                    let signal = map.next_value::<SignalName>()?.0;
+                    set(&mut fields.signal, signal, "signal")?;
+                }
+                Field::CoreDump => set(&mut fields.core_dump, map.next_value()?, "core_dump")?,
+                Field::Stopped => {
+                    let signal = map.next_value::<SignalName>()?.0;
+                    set(&mut fields.stopped, signal, "stopped")?;
+                }
+                Field::Continued => set(&mut fields.continued, map.next_value()?, "continued")?,
+                Field::Raw => set(&mut fields.raw, map.next_value()?, "raw")?,
+                Field::Code => {
+                    let code = map.next_value::<CodeName>()?.0;
+                    set(&mut fields.code, code, "code")?;
+                }
+                Field::NotStarted => {
+                    let result = ProcResult::NotStarted(map.next_value()?);
+                    set_other(&mut fields, "not_started", result)?;
+                }
+                Field::TimedOut => {
+                    let result = ProcResult::TimedOut(map.next_value()?);
+                    set_other(&mut fields, "timed_out", result)?;
+                }
+                Field::LegacyUnix => {
+                    let result = ProcResult::Unix(map.next_value()?);
+                    set_other(&mut fields, "Unix", result)?;
+                }
+                Field::LegacyWindows => {
+                    let result = ProcResult::Windows(map.next_value()?);
+                    set_other(&mut fields, "Windows", result)?;
+                }
+                Field::LegacyNotStarted => {
+                    let result = ProcResult::NotStarted(map.next_value()?);
+                    set_other(&mut fields, "NotStarted", result)?;
+                }
+                Field::LegacyTimedOut => {
+                    let result = ProcResult::TimedOut(map.next_value()?);
+                    set_other(&mut fields, "TimedOut", result)?;
+                }
+                Field::Unknown => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        fields.into_result()
+    }
+}
+
+/// A signal, serialized by name if it has one and the format is human-readable, or by number
+/// otherwise.
+pub(crate) struct SignalName(pub(crate) Signal);
+
+impl Serialize for SignalName {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0.name() {
+            Some(name) if serializer.is_human_readable() => serializer.serialize_str(name),
+            _ => serializer.serialize_u8(self.0.to_raw()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SignalName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SignalVisitor;
+
+        impl Visitor<'_> for SignalVisitor {
+            type Value = SignalName;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a signal name or number")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                u8::try_from(v)
+                    .map(|signal| SignalName(Signal::from_raw(signal)))
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                u8::try_from(v)
+                    .map(|signal| SignalName(Signal::from_raw(signal)))
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                Signal::from_name(v)
+                    .map(SignalName)
+                    .ok_or_else(|| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignalVisitor)
+        } else {
+            deserializer.deserialize_u8(SignalVisitor)
+        }
+    }
+}
+
+/// A Windows exit code, deserialized from a number or any string accepted by `FromStr`.
+struct CodeName(windows::ExitCode);
+
+impl<'de> Deserialize<'de> for CodeName {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CodeVisitor;
+
+        impl Visitor<'_> for CodeVisitor {
+            type Value = CodeName;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a Windows exit code or name")
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                u32::try_from(v)
+                    .map(|code| CodeName(windows::ExitCode::from_raw(code)))
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(v), &self))
+            }
+
+            #[allow(clippy::cast_sign_loss)]
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                i32::try_from(v)
+                    .map(|code| CodeName(windows::ExitCode::from_raw(code as u32)))
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(v), &self))
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                v.parse()
+                    .map(CodeName)
+                    .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(CodeVisitor)
+        } else {
+            deserializer.deserialize_u32(CodeVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SpawnErrorKind;
+
+    fn unix(raw: i32) -> ProcResult {
+        ProcResult::Unix(WaitStatus::from_raw(raw))
+    }
+
+    fn windows(raw: u32) -> ProcResult {
+        ProcResult::Windows(windows::ExitCode::from_raw(raw))
+    }
+
+    fn round_trip(result: ProcResult, json: &str) {
+        assert_eq!(serde_json::to_string(&result).unwrap(), json);
+        assert_eq!(serde_json::from_str::<ProcResult>(json).unwrap(), result);
+    }
+
+    fn read(json: &str) -> ProcResult {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip(
+            unix(0x0100),
+            r#"{"version":1,"platform":"unix","exited":1}"#,
+        );
+        round_trip(
+            unix(9),
+            r#"{"version":1,"platform":"unix","signal":"SIGKILL","core_dump":false}"#,
+        );
+        round_trip(
+            unix(0x8B),
+            r#"{"version":1,"platform":"unix","signal":"SIGSEGV","core_dump":true}"#,
+        );
+        round_trip(
+            unix(0x40),
+            r#"{"version":1,"platform":"unix","signal":64,"core_dump":false}"#,
+        );
+        round_trip(
+            unix(0x147F),
+            r#"{"version":1,"platform":"unix","stopped":"SIGTSTP"}"#,
+        );
+        round_trip(
+            unix(0xFFFF),
+            r#"{"version":1,"platform":"unix","continued":true}"#,
+        );
+        round_trip(
+            windows(0xC000_0005),
+            r#"{"version":1,"platform":"windows","code":3221225477}"#,
+        );
+        round_trip(
+            ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::NotFound, Some(2))),
+            r#"{"version":1,"not_started":{"kind":"NotFound","raw_os_error":2}}"#,
+        );
+        round_trip(
+            ProcResult::TimedOut(Some(Duration::from_secs(5))),
+            r#"{"version":1,"timed_out":{"secs":5,"nanos":0}}"#,
+        );
+        round_trip(
+            ProcResult::TimedOut(None),
+            r#"{"version":1,"timed_out":null}"#,
+        );
+    }
+
+    #[test]
+    fn test_deserialize_alternate_forms() {
+        assert_eq!(read(r#"{"platform":"unix","raw":256}"#), unix(0x0100));
+        assert_eq!(
+            read(r#"{"version":1,"platform":"unix","signal":15,"comment":"ok"}"#),
+            unix(15)
+        );
+        assert_eq!(
+            read(r#"{"platform":"windows","code":"STATUS_ACCESS_VIOLATION"}"#),
+            windows(0xC000_0005)
+        );
+        assert_eq!(
+            read(r#"{"platform":"windows","code":-1073741819}"#),
+            windows(0xC000_0005)
+        );
+        assert_eq!(
+            read(r#"{"platform":"windows","code":"0xC0000005"}"#),
+            windows(0xC000_0005)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy() {
+        assert_eq!(read(r#"{"Unix":256}"#), unix(0x0100));
+        assert_eq!(read(r#"{"Windows":1}"#), windows(1));
+        assert_eq!(
+            read(r#"{"NotStarted":{"kind":"NotFound","raw_os_error":null}}"#),
+            ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::NotFound, None))
+        );
+        assert_eq!(read(r#"{"TimedOut":null}"#), ProcResult::TimedOut(None));
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let error = |json| {
+            serde_json::from_str::<ProcResult>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(r#"{"version":2,"platform":"unix","exited":1}"#),
+            "invalid value: integer `2`, expected format version 1 at line 1 column 42"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix"}"#),
+            "missing field `exited` at line 1 column 19"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","signal":"SIGFOO"}"#),
+            "invalid value: string \"SIGFOO\", expected a signal name or number \
+             at line 1 column 36"
+        );
+        assert_eq!(error("{}"), "missing field `platform` at line 1 column 2");
+        for (json, signal, column) in [
+            (r#"{"platform":"unix","signal":0}"#, 0, 30),
+            (r#"{"platform":"unix","signal":127}"#, 127, 32),
+            (
+                r#"{"platform":"unix","signal":200,"core_dump":true}"#,
+                200,
+                49,
+            ),
+            (r#"{"platform":"unix","stopped":0}"#, 0, 31),
+        ] {
+            assert_eq!(
+                error(json),
+                alloc::format!(
+                    "invalid value: integer `{signal}`, expected a signal that a wait status can \
+                     hold at line 1 column {column}"
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_deserialize_conflicts() {
+        let error = |json| {
+            serde_json::from_str::<ProcResult>(json)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error(r#"{"platform":"unix","exited":1,"signal":"SIGKILL"}"#),
+            "conflicting fields `exited` and `signal` at line 1 column 49"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","raw":9,"exited":1}"#),
+            "conflicting fields `exited` and `raw` at line 1 column 38"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","exited":1,"core_dump":true}"#),
+            "conflicting fields `exited` and `core_dump` at line 1 column 47"
+        );
+        assert_eq!(
+            error(r#"{"platform":"windows","code":1,"exited":1}"#),
+            "conflicting fields `code` and `exited` at line 1 column 42"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","exited":1,"timed_out":null}"#),
+            "conflicting fields `exited` and `timed_out` at line 1 column 47"
+        );
+        assert_eq!(
+            error(r#"{"platform":"windows","code":1,"Windows":1}"#),
+            "conflicting fields `code` and `Windows` at line 1 column 43"
+        );
+        assert_eq!(
+            error(r#"{"timed_out":null,"Unix":0}"#),
+            "conflicting fields `timed_out` and `Unix` at line 1 column 27"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","exited":1,"exited":2}"#),
+            "duplicate field `exited` at line 1 column 41"
+        );
+        assert_eq!(
+            error(r#"{"timed_out":null,"timed_out":null}"#),
+            "duplicate field `timed_out` at line 1 column 35"
+        );
+        assert_eq!(
+            error(r#"{"platform":"unix","continued":false}"#),
+            "invalid value: boolean `false`, expected true at line 1 column 37"
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        for result in [
+            unix(0x0100),
+            unix(0x8B),
+            unix(0x147F),
+            windows(0xC000_0005),
+            ProcResult::NotStarted(SpawnError::new(SpawnErrorKind::NotFound, Some(2))),
+            ProcResult::TimedOut(Some(Duration::from_secs(5))),
+        ] {
+            let bytes = bincode::serialize(&result).unwrap();
+            assert_eq!(bincode::deserialize::<ProcResult>(&bytes).unwrap(), result);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use core::fmt::Display;
+
+/// Why a process could not be started.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SpawnErrorKind {
+    /// The program was not found.
+    NotFound,
+
+    /// The program was found, but could not be executed due to its permissions.
+    PermissionDenied,
+
+    /// The program was found, but is not an executable the platform can run, i.e. a script
+    /// without a shebang line, or a binary built for another architecture.
+    ExecFormat,
+
+    /// Any other error.
+    Other,
+}
+
+impl Display for SpawnErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::NotFound => "program not found",
+            Self::PermissionDenied => "permission denied",
+            Self::ExecFormat => "exec format error",
+            Self::Other => "spawn error",
+        })
+    }
+}
+
+/// An error that prevented a process from being started.
+///
+/// Unlike [`std::io::Error`], a `SpawnError` is `Copy` and can be compared and serialized, so it
+/// can be recorded as a [`ProcResult::NotStarted`](crate::ProcResult::NotStarted):
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # {
+/// use proc_result::{ProcResult, SpawnErrorKind};
+///
+/// let error = std::process::Command::new("does-not-exist").status().unwrap_err();
+/// let result = ProcResult::NotStarted(error.into());
+/// assert!(result.is_failure());
+///
+/// let ProcResult::NotStarted(error) = result else { unreachable!() };
+/// assert_eq!(error.kind(), SpawnErrorKind::NotFound);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpawnError {
+    kind: SpawnErrorKind,
+    raw_os_error: Option<i32>,
+}
+
+impl SpawnError {
+    /// Creates a new `SpawnError` of the given kind, with an optional OS error code.
+    #[must_use]
+    pub const fn new(kind: SpawnErrorKind, raw_os_error: Option<i32>) -> Self {
+        Self { kind, raw_os_error }
+    }
+
+    /// Returns the kind of error.
+    #[must_use]
+    pub const fn kind(&self) -> SpawnErrorKind {
+        self.kind
+    }
+
+    /// Returns the OS error code that caused the error, if any.
+    ///
+    /// The code is specific to the platform the process was spawned on, i.e. `errno` on Unix.
+    #[must_use]
+    pub const fn raw_os_error(&self) -> Option<i32> {
+        self.raw_os_error
+    }
+}
+
+impl Display for SpawnError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.raw_os_error {
+            Some(code) => write!(f, "{} (os error {code})", self.kind),
+            None => write!(f, "{}", self.kind),
+        }
+    }
+}
+
+impl core::error::Error for SpawnError {}
+
+/// The OS error codes of an exec format error on the current platform.
+#[cfg(all(feature = "std", unix))]
+const EXEC_FORMAT_ERRORS: &[i32] = &[
+    8, // ENOEXEC
+];
+
+/// The OS error codes of an exec format error on the current platform.
+#[cfg(all(feature = "std", windows))]
+const EXEC_FORMAT_ERRORS: &[i32] = &[
+    193, // ERROR_BAD_EXE_FORMAT
+    216, // ERROR_EXE_MACHINE_TYPE_MISMATCH
+];
+
+/// The OS error codes of an exec format error on the current platform.
+#[cfg(all(feature = "std", not(any(unix, windows))))]
+const EXEC_FORMAT_ERRORS: &[i32] = &[];
+
+#[cfg(feature = "std")]
+impl From<&std::io::Error> for SpawnError {
+    fn from(error: &std::io::Error) -> Self {
+        let raw_os_error = error.raw_os_error();
+        let kind = match error.kind() {
+            std::io::ErrorKind::NotFound => SpawnErrorKind::NotFound,
+            std::io::ErrorKind::PermissionDenied => SpawnErrorKind::PermissionDenied,
+            _ if raw_os_error.is_some_and(|code| EXEC_FORMAT_ERRORS.contains(&code)) => {
+                SpawnErrorKind::ExecFormat
+            }
+            _ => SpawnErrorKind::Other,
+        };
+        Self { kind, raw_os_error }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for SpawnError {
+    fn from(error: std::io::Error) -> Self {
+        Self::from(&error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let error = SpawnError::new(SpawnErrorKind::NotFound, Some(2));
+        assert_eq!(error.to_string(), "program not found (os error 2)");
+
+        let error = SpawnError::new(SpawnErrorKind::Other, None);
+        assert_eq!(error.to_string(), "spawn error");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_from_io_error() {
+        let error = SpawnError::from(std::io::Error::from(std::io::ErrorKind::PermissionDenied));
+        assert_eq!(error.kind(), SpawnErrorKind::PermissionDenied);
+        assert_eq!(error.raw_os_error(), None);
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", unix))]
+    fn test_from_io_error_exec_format() {
+        let error = SpawnError::from(std::io::Error::from_raw_os_error(libc::ENOEXEC));
+        assert_eq!(error.kind(), SpawnErrorKind::ExecFormat);
+        assert_eq!(error.raw_os_error(), Some(libc::ENOEXEC));
+    }
+}
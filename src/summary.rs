@@ -0,0 +1,485 @@
+//! Summaries of many results, i.e. from a test runner.
+//!
+//! A [`Summary`] counts results by [`Severity`], collects the distinct signals and exit codes
+//! that were seen, and keeps the worst result:
+//!
+//! ```rust
+//! use proc_result::{ProcResult, Severity};
+//! use proc_result::summary::Summary;
+//! use proc_result::unix::{Signal, WaitStatus};
+//!
+//! let summary: Summary = [0, 0x0100, 9, 0]
+//!     .into_iter()
+//!     .map(|raw| ProcResult::Unix(WaitStatus::from_raw(raw)))
+//!     .collect();
+//!
+//! assert_eq!(summary.total(), 4);
+//! assert_eq!(summary.count(Severity::Success), 2);
+//! assert!(summary.signals().contains(&Signal::KILL));
+//! assert_eq!(summary.worst(), Some(&ProcResult::Unix(WaitStatus::from_raw(9))));
+//! ```
+//!
+//! The [`Display`] implementation prints a report table:
+//!
+//! ```text
+//! outcome      count
+//! success          2
+//! failure          1
+//! timed out        0
+//! signaled         1
+//! core dumped      0
+//! total            4
+//!
+//! signals: SIGKILL (9)
+//! exit codes: 1
+//! worst: killed by signal SIGKILL (9)
+//! ```
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt::{self, Display};
+
+use crate::{ProcResult, Severity, unix, windows};
+
+/// Counts, distinct signals and exit codes, and the worst of many results.
+///
+/// With the `serde` feature, signals are serialized by name, as in [`ProcResult`], and summaries
+/// are checked when deserialized, i.e. that `total` is the sum of the counts and that `worst` is
+/// of the highest severity that was counted.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "Fields")
+)]
+pub struct Summary {
+    total: usize,
+    counts: BTreeMap<Severity, usize>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_signals"))]
+    signals: BTreeSet<unix::Signal>,
+    exit_codes: BTreeSet<unix::ExitCode>,
+    windows_exit_codes: BTreeSet<windows::ExitCode>,
+    worst: Option<ProcResult>,
+}
+
+impl Summary {
+    /// Creates an empty summary.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            total: 0,
+            counts: BTreeMap::new(),
+            signals: BTreeSet::new(),
+            exit_codes: BTreeSet::new(),
+            windows_exit_codes: BTreeSet::new(),
+            worst: None,
+        }
+    }
+
+    /// Adds a result to the summary.
+    ///
+    /// The worst result is the first one of the highest [`Severity`] that was added.
+    pub fn add(&mut self, result: ProcResult) {
+        self.total += 1;
+        *self.counts.entry(result.severity()).or_default() += 1;
+        match result {
+            ProcResult::Unix(status) => {
+                if let Some(signal) = status.signal().or(status.stop_signal()) {
+                    self.signals.insert(signal);
+                }
+                if let Some(code) = status.exit_code().filter(|code| !code.is_success()) {
+                    self.exit_codes.insert(code);
+                }
+            }
+            ProcResult::Windows(code) if !code.is_success() => {
+                self.windows_exit_codes.insert(code);
+            }
+            ProcResult::Windows(_) | ProcResult::NotStarted(_) | ProcResult::TimedOut(_) => {}
+        }
+        if self
+            .worst
+            .is_none_or(|worst| result.cmp_severity(&worst).is_gt())
+        {
+            self.worst = Some(result);
+        }
+    }
+
+    /// Adds the results of another summary to this one.
+    ///
+    /// The worst result of `self` is kept over one of the same severity in `other`.
+    pub fn merge(&mut self, other: Self) {
+        self.total += other.total;
+        for (severity, count) in other.counts {
+            *self.counts.entry(severity).or_default() += count;
+        }
+        self.signals.extend(other.signals);
+        self.exit_codes.extend(other.exit_codes);
+        self.windows_exit_codes.extend(other.windows_exit_codes);
+        if let Some(result) = other.worst
+            && self
+                .worst
+                .is_none_or(|worst| result.cmp_severity(&worst).is_gt())
+        {
+            self.worst = Some(result);
+        }
+    }
+
+    /// Returns the number of results.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the number of results of a [`Severity`].
+    #[must_use]
+    pub fn count(&self, severity: Severity) -> usize {
+        self.counts.get(&severity).copied().unwrap_or_default()
+    }
+
+    /// Returns the distinct Unix signals that terminated or stopped a process.
+    #[must_use]
+    pub const fn signals(&self) -> &BTreeSet<unix::Signal> {
+        &self.signals
+    }
+
+    /// Returns the distinct non-zero Unix exit codes.
+    #[must_use]
+    pub const fn exit_codes(&self) -> &BTreeSet<unix::ExitCode> {
+        &self.exit_codes
+    }
+
+    /// Returns the distinct non-zero Windows exit codes.
+    #[must_use]
+    pub const fn windows_exit_codes(&self) -> &BTreeSet<windows::ExitCode> {
+        &self.windows_exit_codes
+    }
+
+    /// Returns the worst result, or `None` if the summary is empty.
+    #[must_use]
+    pub const fn worst(&self) -> Option<&ProcResult> {
+        self.worst.as_ref()
+    }
+
+    /// Returns `true` if every result was successful.
+    ///
+    /// An empty summary is successful.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.count(Severity::Success) == self.total
+    }
+}
+
+impl Extend<ProcResult> for Summary {
+    fn extend<I: IntoIterator<Item = ProcResult>>(&mut self, results: I) {
+        for result in results {
+            self.add(result);
+        }
+    }
+}
+
+impl FromIterator<ProcResult> for Summary {
+    fn from_iter<I: IntoIterator<Item = ProcResult>>(results: I) -> Self {
+        let mut summary = Self::new();
+        summary.extend(results);
+        summary
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:<11}  {:>5}", "outcome", "count")?;
+        for severity in Severity::ALL {
+            writeln!(f, "{severity:<11}  {:>5}", self.count(severity))?;
+        }
+        write!(f, "{:<11}  {:>5}", "total", self.total)?;
+
+        // The lines after the table are separated from it by a blank line.
+        let mut separator = "\n\n";
+        let signals = self.signals.iter().map(|&signal| SignalName(signal));
+        write_line(f, &mut separator, "signals", signals)?;
+        write_line(f, &mut separator, "exit codes", &self.exit_codes)?;
+        let windows_exit_codes = self
+            .windows_exit_codes
+            .iter()
+            .map(|code| code.format(windows::ExitCodeFormat::Hex));
+        write_line(f, &mut separator, "windows exit codes", windows_exit_codes)?;
+        write_line(f, &mut separator, "worst", self.worst)
+    }
+}
+
+/// The fields of a serialized [`Summary`], as they are read before they are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Fields {
+    total: usize,
+    counts: BTreeMap<Severity, usize>,
+    #[serde(deserialize_with = "deserialize_signals")]
+    signals: BTreeSet<unix::Signal>,
+    exit_codes: BTreeSet<unix::ExitCode>,
+    windows_exit_codes: BTreeSet<windows::ExitCode>,
+    worst: Option<ProcResult>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Fields> for Summary {
+    type Error = &'static str;
+
+    fn try_from(mut fields: Fields) -> Result<Self, Self::Error> {
+        // Severities that were never counted are not serialized, so they are not kept either.
+        fields.counts.retain(|_, count| *count > 0);
+        let sum = fields
+            .counts
+            .values()
+            .try_fold(0_usize, |sum, &count| sum.checked_add(count));
+        if sum != Some(fields.total) {
+            return Err("`total` is not the sum of `counts`");
+        }
+        let highest = fields.counts.keys().next_back().copied();
+        if fields.worst.map(|worst| worst.severity()) != highest {
+            return Err("`worst` is not of the highest severity in `counts`");
+        }
+        if fields.exit_codes.iter().any(unix::ExitCode::is_success)
+            || fields
+                .windows_exit_codes
+                .iter()
+                .any(windows::ExitCode::is_success)
+        {
+            return Err("`exit_codes` contains a successful exit code");
+        }
+        Ok(Self {
+            total: fields.total,
+            counts: fields.counts,
+            signals: fields.signals,
+            exit_codes: fields.exit_codes,
+            windows_exit_codes: fields.windows_exit_codes,
+            worst: fields.worst,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_signals<S: serde::Serializer>(
+    signals: &BTreeSet<unix::Signal>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    use crate::serialize::SignalName;
+
+    serializer.collect_seq(signals.iter().map(|&signal| SignalName(signal)))
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_signals<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeSet<unix::Signal>, D::Error> {
+    use crate::serialize::SignalName;
+
+    let signals: alloc::vec::Vec<SignalName> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(signals
+        .into_iter()
+        .map(|SignalName(signal)| signal)
+        .collect())
+}
+
+/// Writes `label: a, b, c` after `separator`, or nothing if there are no items.
+fn write_line<T: Display>(
+    f: &mut fmt::Formatter<'_>,
+    separator: &mut &str,
+    label: &str,
+    items: impl IntoIterator<Item = T>,
+) -> fmt::Result {
+    let mut items = items.into_iter().peekable();
+    if items.peek().is_none() {
+        return Ok(());
+    }
+    write!(f, "{separator}{label}:")?;
+    *separator = "\n";
+    for (index, item) in items.enumerate() {
+        let comma = if index == 0 { "" } else { "," };
+        write!(f, "{comma} {item}")?;
+    }
+    Ok(())
+}
+
+/// Displays a signal by name and number, i.e. `SIGKILL (9)`, or by number if it has no name.
+struct SignalName(unix::Signal);
+
+impl Display for SignalName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0.name() {
+            Some(name) => write!(f, "{name} ({})", self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::unix::WaitStatus;
+
+    fn unix(raw: i32) -> ProcResult {
+        ProcResult::Unix(WaitStatus::from_raw(raw))
+    }
+
+    fn windows(raw: u32) -> ProcResult {
+        ProcResult::Windows(windows::ExitCode::from_raw(raw))
+    }
+
+    #[test]
+    fn test_add() {
+        let summary: Summary = [0, 0x0100, 0x0200, 0x0100, 9, 0x8B, 0x0B]
+            .into_iter()
+            .map(unix)
+            .collect();
+        assert_eq!(summary.total(), 7);
+        assert_eq!(summary.count(Severity::Success), 1);
+        assert_eq!(summary.count(Severity::Failure), 3);
+        assert_eq!(summary.count(Severity::TimedOut), 0);
+        assert_eq!(summary.count(Severity::Signaled), 2);
+        assert_eq!(summary.count(Severity::CoreDumped), 1);
+        assert_eq!(
+            summary.signals().iter().copied().collect::<Vec<_>>(),
+            [unix::Signal::KILL, unix::Signal::SEGMENTATION_VIOLATION]
+        );
+        assert_eq!(
+            summary
+                .exit_codes()
+                .iter()
+                .map(unix::ExitCode::to_raw)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+        assert_eq!(summary.worst(), Some(&unix(0x8B)));
+        assert!(!summary.is_success());
+    }
+
+    #[test]
+    fn test_worst_is_first_of_severity() {
+        let summary: Summary = [unix(0x0100), unix(0x0200), windows(1)]
+            .into_iter()
+            .collect();
+        assert_eq!(summary.worst(), Some(&unix(0x0100)));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut summary: Summary = [unix(0), unix(0x0100)].into_iter().collect();
+        let other: Summary = [windows(0xC000_0005), unix(0x0100)].into_iter().collect();
+        summary.merge(other);
+
+        let expected: Summary = [unix(0), unix(0x0100), windows(0xC000_0005), unix(0x0100)]
+            .into_iter()
+            .collect();
+        assert_eq!(summary, expected);
+    }
+
+    #[test]
+    fn test_empty() {
+        let summary = Summary::new();
+        assert_eq!(summary.worst(), None);
+        assert!(summary.is_success());
+        assert_eq!(
+            summary.to_string(),
+            "outcome      count\n\
+             success          0\n\
+             failure          0\n\
+             timed out        0\n\
+             signaled         0\n\
+             core dumped      0\n\
+             total            0"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let summary: Summary = [unix(0), unix(0x0100), unix(9), windows(0xC000_0005)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            summary.to_string(),
+            "outcome      count\n\
+             success          1\n\
+             failure          1\n\
+             timed out        0\n\
+             signaled         2\n\
+             core dumped      0\n\
+             total            4\n\
+             \n\
+             signals: SIGKILL (9)\n\
+             exit codes: 1\n\
+             windows exit codes: 0xC0000005\n\
+             worst: killed by signal SIGKILL (9)"
+        );
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use crate::unix::WaitStatus;
+
+    #[test]
+    fn test_round_trip() {
+        let summary: Summary = [0, 0x0100, 9]
+            .into_iter()
+            .map(|raw| ProcResult::Unix(WaitStatus::from_raw(raw)))
+            .collect();
+        let json = serde_json::to_string(&summary).unwrap();
+        assert_eq!(
+            json,
+            concat!(
+                r#"{"total":3,"counts":{"success":1,"failure":1,"signaled":1},"#,
+                r#""signals":["SIGKILL"],"exit_codes":[1],"windows_exit_codes":[],"#,
+                r#""worst":{"version":1,"platform":"unix","signal":"SIGKILL","core_dump":false}}"#,
+            )
+        );
+        assert_eq!(serde_json::from_str::<Summary>(&json).unwrap(), summary);
+    }
+    #[test]
+    fn test_deserialize_invalid() {
+        let read = |total: usize, counts: &str, exit_codes: &str, worst: &str| {
+            serde_json::from_str::<Summary>(&alloc::format!(
+                "{{\"total\":{total},\"counts\":{counts},\"signals\":[],\
+                 \"exit_codes\":{exit_codes},\"windows_exit_codes\":[],\"worst\":{worst}}}"
+            ))
+            .map_err(|error| error.to_string())
+        };
+        let exited = r#"{"platform":"unix","exited":1}"#;
+        let sum = Err("`total` is not the sum of `counts`".into());
+        let worst = Err("`worst` is not of the highest severity in `counts`".into());
+        assert_eq!(read(2, r#"{"failure":1}"#, "[1]", exited), sum);
+        assert_eq!(read(1, r#"{"failure":1}"#, "[1]", "null"), worst);
+        assert_eq!(read(0, "{}", "[]", exited), worst);
+        assert_eq!(
+            read(2, r#"{"failure":1,"signaled":1}"#, "[1]", exited),
+            worst
+        );
+
+        let overflow = alloc::format!(r#"{{"success":1,"failure":{}}}"#, usize::MAX);
+        assert_eq!(read(0, &overflow, "[1]", exited), sum);
+        assert_eq!(
+            read(1, r#"{"failure":1}"#, "[0,1]", exited),
+            Err("`exit_codes` contains a successful exit code".into())
+        );
+
+        // Zero counts and signals written as numbers are accepted.
+        assert_eq!(
+            read(0, r#"{"success":0}"#, "[]", "null"),
+            Ok(Summary::new())
+        );
+        let summary: Summary = serde_json::from_str(concat!(
+            r#"{"total":1,"counts":{"signaled":1},"signals":[9],"exit_codes":[],"#,
+            r#""windows_exit_codes":[],"#,
+            r#""worst":{"platform":"unix","signal":"SIGKILL","core_dump":false}}"#,
+        ))
+        .unwrap();
+        assert_eq!(
+            summary,
+            [ProcResult::Unix(WaitStatus::from_raw(9))]
+                .into_iter()
+                .collect()
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use core::fmt::Display;
 
+use crate::explain::{self, Explanation};
 use crate::raw::RawExitCode;
 
 /// A Unix-like exit code.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -156,8 +157,59 @@ impl ExitCode {
     pub const fn is_failure(&self) -> bool {
         !self.is_success()
     }
+
+    /// Returns the symbolic name of the exit code from `sysexits.h`, if it has one.
+    ///
+    /// For example, `75` is `EX_TEMPFAIL`.
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|(_, name)| *name)
+    }
+
+    /// Returns the exit code with the given symbolic name from `sysexits.h`, if known.
+    ///
+    /// The comparison is ASCII case-insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(code, _)| *code)
+    }
+
+    /// Returns a diagnostic explanation of the exit code, if one is known.
+    ///
+    /// Exit codes `129` to `159` are explained as a termination by signal `code - 128`, which is
+    /// how shells report them.
+    #[must_use]
+    pub fn explain(&self) -> Option<Explanation> {
+        explain::unix_exit_code(*self)
+    }
 }
 
+/// Symbolic names of exit codes, from `sysexits.h`.
+const NAMES: &[(ExitCode, &str)] = &[
+    (ExitCode::SUCCESS, "EX_OK"),
+    (ExitCode::USAGE, "EX_USAGE"),
+    (ExitCode::DATA_ERROR, "EX_DATAERR"),
+    (ExitCode::NO_INPUT, "EX_NOINPUT"),
+    (ExitCode::NO_USER, "EX_NOUSER"),
+    (ExitCode::NO_HOST, "EX_NOHOST"),
+    (ExitCode::UNAVAILABLE, "EX_UNAVAILABLE"),
+    (ExitCode::SOFTWARE, "EX_SOFTWARE"),
+    (ExitCode::OS_ERROR, "EX_OSERR"),
+    (ExitCode::OS_FILE, "EX_OSFILE"),
+    (ExitCode::CANT_CREATE, "EX_CANTCREAT"),
+    (ExitCode::IO_ERROR, "EX_IOERR"),
+    (ExitCode::TEMP_FAIL, "EX_TEMPFAIL"),
+    (ExitCode::PROTOCOL, "EX_PROTOCOL"),
+    (ExitCode::NO_PERM, "EX_NOPERM"),
+    (ExitCode::CONFIG, "EX_CONFIG"),
+];
+
 impl Display for ExitCode {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.0.fmt(f)
@@ -201,6 +253,17 @@ mod tests {
         assert!(ExitCode::GENERAL_ERROR.is_failure());
     }
 
+    #[test]
+    fn test_name() {
+        assert_eq!(ExitCode::TEMP_FAIL.name(), Some("EX_TEMPFAIL"));
+        assert_eq!(ExitCode::GENERAL_ERROR.name(), None);
+        assert_eq!(
+            ExitCode::from_name("ex_tempfail"),
+            Some(ExitCode::TEMP_FAIL)
+        );
+        assert_eq!(ExitCode::from_name("EX_UNKNOWN"), None);
+    }
+
     #[test]
     fn test_from_u8() {
         let code: ExitCode = 1.into();
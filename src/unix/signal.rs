@@ -1,9 +1,11 @@
 use core::fmt::Display;
 
+use crate::explain::{self, Explanation};
+
 /// A Unix-like signal.
 ///
 /// Represents a signal that can be sent to or received by processes on Unix-like systems.
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -24,6 +26,104 @@ impl Signal {
         self.0
     }
 
+    /// Returns the symbolic name of the signal, i.e. `SIGKILL`, or `None` if it is not known.
+    ///
+    /// Names follow the Linux numbering used by the constants of this type.
+    #[must_use]
+    pub const fn name(&self) -> Option<&'static str> {
+        Some(match self.0 {
+            1 => "SIGHUP",
+            2 => "SIGINT",
+            3 => "SIGQUIT",
+            4 => "SIGILL",
+            5 => "SIGTRAP",
+            6 => "SIGABRT",
+            7 => "SIGBUS",
+            8 => "SIGFPE",
+            9 => "SIGKILL",
+            10 => "SIGUSR1",
+            11 => "SIGSEGV",
+            12 => "SIGUSR2",
+            13 => "SIGPIPE",
+            14 => "SIGALRM",
+            15 => "SIGTERM",
+            16 => "SIGSTKFLT",
+            17 => "SIGCHLD",
+            18 => "SIGCONT",
+            19 => "SIGSTOP",
+            20 => "SIGTSTP",
+            21 => "SIGTTIN",
+            22 => "SIGTTOU",
+            23 => "SIGURG",
+            24 => "SIGXCPU",
+            25 => "SIGXFSZ",
+            26 => "SIGVTALRM",
+            27 => "SIGPROF",
+            28 => "SIGWINCH",
+            29 => "SIGIO",
+            30 => "SIGPWR",
+            31 => "SIGSYS",
+            _ => return None,
+        })
+    }
+
+    /// Returns the signal with the given symbolic name, i.e. `SIGKILL`, if known.
+    ///
+    /// The comparison is ASCII case-insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        (1..=31)
+            .map(Self)
+            .find(|signal| signal.name().is_some_and(|n| n.eq_ignore_ascii_case(name)))
+    }
+
+    /// Returns a description of the signal, i.e. `Segmentation fault`, or `None` if not known.
+    ///
+    /// Descriptions match those of `strsignal` in glibc, which shells print for terminated jobs.
+    #[must_use]
+    pub const fn description(&self) -> Option<&'static str> {
+        Some(match self.0 {
+            1 => "Hangup",
+            2 => "Interrupt",
+            3 => "Quit",
+            4 => "Illegal instruction",
+            5 => "Trace/breakpoint trap",
+            6 => "Aborted",
+            7 => "Bus error",
+            8 => "Floating point exception",
+            9 => "Killed",
+            10 => "User defined signal 1",
+            11 => "Segmentation fault",
+            12 => "User defined signal 2",
+            13 => "Broken pipe",
+            14 => "Alarm clock",
+            15 => "Terminated",
+            16 => "Stack fault",
+            17 => "Child exited",
+            18 => "Continued",
+            19 => "Stopped (signal)",
+            20 => "Stopped",
+            21 => "Stopped (tty input)",
+            22 => "Stopped (tty output)",
+            23 => "Urgent I/O condition",
+            24 => "CPU time limit exceeded",
+            25 => "File size limit exceeded",
+            26 => "Virtual timer expired",
+            27 => "Profiling timer expired",
+            28 => "Window changed",
+            29 => "I/O possible",
+            30 => "Power failure",
+            31 => "Bad system call",
+            _ => return None,
+        })
+    }
+
+    /// Returns a diagnostic explanation of a termination by the signal, if one is known.
+    #[must_use]
+    pub fn explain(&self) -> Option<Explanation> {
+        explain::signal(*self)
+    }
+
     /// Null signal.
     ///
     /// Corresponds to signal number `0`.
@@ -276,6 +376,26 @@ impl Display for Signal {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_name() {
+        assert_eq!(Signal::KILL.name(), Some("SIGKILL"));
+        assert_eq!(Signal::BAD_SYSTEM_CALL.name(), Some("SIGSYS"));
+        assert_eq!(Signal::NULL.name(), None);
+        assert_eq!(Signal::from_raw(64).name(), None);
+        assert_eq!(Signal::from_name("sigterm"), Some(Signal::TERMINATION));
+        assert_eq!(Signal::from_name("SIGRTMIN"), None);
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(
+            Signal::SEGMENTATION_VIOLATION.description(),
+            Some("Segmentation fault")
+        );
+        assert_eq!(Signal::TTY_STOP.description(), Some("Stopped"));
+        assert_eq!(Signal::from_raw(64).description(), None);
+    }
+
     #[test]
     fn test_from_raw() {
         assert_eq!(Signal::from_raw(1).to_raw(), 1);
@@ -6,7 +6,10 @@ use super::{ExitCode, Signal};
 /// terminated by a signal, stopped, or continued, and to retrieve the exit code or signal number
 /// associated with the process's termination or stopping, without dependence on external crates
 /// such as `libc`.
+///
+/// More states may be added in the future, so matches must include a wildcard arm.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum WaitState {
     /// Indicates that the process exited normally with a specific exit code.
     Exited {
@@ -23,6 +26,15 @@ pub enum WaitState {
         core_dump: bool,
     },
 
+    /// Indicates that the process was stopped by a signal, and may be continued later.
+    Stopped {
+        /// The signal that caused the process to stop.
+        signal: Signal,
+    },
+
+    /// Indicates that a stopped process was continued by `SIGCONT`.
+    Continued,
+
     /// Indicates a wait status code that is not recognized or supported.
     Unsupported(i32),
 }
@@ -35,6 +47,12 @@ impl WaitState {
             Self::Exited {
                 exit_code: ExitCode::from_raw(Self::w_exit_status(status)),
             }
+        } else if Self::is_w_continued(status) {
+            Self::Continued
+        } else if Self::is_w_stopped(status) {
+            Self::Stopped {
+                signal: Signal::from_raw(Self::w_stop_sig(status)),
+            }
         } else if Self::is_w_signaled(status) {
             Self::Signaled {
                 signal: Signal::from_raw(Self::w_term_sig(status)),
@@ -60,13 +78,30 @@ impl WaitState {
             Self::Signaled { signal, core_dump } => {
                 (signal.to_raw() as i32) | if *core_dump { 0x80 } else { 0 }
             }
+            Self::Stopped { signal } => ((signal.to_raw() as i32) << 8) | Self::_WSTOPPED,
+            Self::Continued => Self::_WCONTINUED,
             Self::Unsupported(code) => *code,
         }
     }
 
+    /// Returns `true` if [`WaitState::to_raw`] encodes this state as a status that decodes to it.
+    ///
+    /// A signal of `0`, or of `0x7F` or more, would be read as an exit, a stop or a core dump
+    /// instead, and a stop signal of `0` is never reported.
+    pub(crate) const fn is_encodable(self) -> bool {
+        match self {
+            Self::Signaled { signal, .. } => matches!(signal.to_raw(), 1..0x7F),
+            Self::Stopped { signal } => signal.to_raw() != 0,
+            Self::Exited { .. } | Self::Continued | Self::Unsupported(_) => true,
+        }
+    }
+
     /// Represents the stopped status bit.
     const _WSTOPPED: i32 = 0x7F;
 
+    /// Represents the continued status code.
+    const _WCONTINUED: i32 = 0xFFFF;
+
     /// A copy of the Unix `_WSTATUS(status)` macro.
     #[allow(non_snake_case)]
     #[inline]
@@ -107,6 +142,30 @@ impl WaitState {
         (status >> 8) & 0xFF
     }
 
+    /// A copy of the Unix `WIFSTOPPED(status)` macro.
+    #[allow(non_snake_case)]
+    #[inline]
+    #[must_use]
+    const fn WIFSTOPPED(status: i32) -> bool {
+        Self::_WSTATUS(status) == Self::_WSTOPPED
+    }
+
+    /// A copy of the Unix `WSTOPSIG(status)` macro.
+    #[allow(non_snake_case)]
+    #[inline]
+    #[must_use]
+    const fn WSTOPSIG(status: i32) -> i32 {
+        (status >> 8) & 0xFF
+    }
+
+    /// A copy of the (Linux) Unix `WIFCONTINUED(status)` macro.
+    #[allow(non_snake_case)]
+    #[inline]
+    #[must_use]
+    const fn WIFCONTINUED(status: i32) -> bool {
+        status == Self::_WCONTINUED
+    }
+
     /// A copy of the Unix `WCOREDUMP(status)` macro.
     #[allow(non_snake_case)]
     #[inline]
@@ -157,6 +216,31 @@ impl WaitState {
         Self::WTERMSIG(status) as u8
     }
 
+    /// Returns `true` if the status indicates that the process was stopped by a signal.
+    ///
+    /// Equivalent to the Unix `WIFSTOPPED(status)` macro.
+    #[must_use]
+    pub const fn is_w_stopped(status: i32) -> bool {
+        Self::WIFSTOPPED(status)
+    }
+
+    /// Returns the signal number that caused the process to stop.
+    ///
+    /// Equivalent to the Unix `WSTOPSIG(status)` macro.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    #[must_use]
+    pub const fn w_stop_sig(status: i32) -> u8 {
+        Self::WSTOPSIG(status) as u8
+    }
+
+    /// Returns `true` if the status indicates that a stopped process was continued.
+    ///
+    /// Equivalent to the Unix `WIFCONTINUED(status)` macro, as defined on Linux.
+    #[must_use]
+    pub const fn is_w_continued(status: i32) -> bool {
+        Self::WIFCONTINUED(status)
+    }
+
     /// Returns `true` if the status indicates a core dump occurred.
     ///
     /// Equivalent to the Unix `WCOREDUMP(status)` macro.
@@ -260,13 +344,38 @@ mod tests {
         };
         assert_eq!(status.to_raw(), 0x0000_0081);
     }
+
+    #[test]
+    fn test_from_raw_stopped() {
+        let status = WaitState::from_raw(0x0000_137F);
+        assert_eq!(
+            status,
+            WaitState::Stopped {
+                signal: Signal::STOP,
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_raw_stopped() {
+        let status = WaitState::Stopped {
+            signal: Signal::TTY_STOP,
+        };
+        assert_eq!(status.to_raw(), 0x0000_147F);
+    }
+
+    #[test]
+    fn test_from_raw_continued() {
+        assert_eq!(WaitState::from_raw(0x0000_FFFF), WaitState::Continued);
+        assert_eq!(WaitState::Continued.to_raw(), 0x0000_FFFF);
+    }
 }
 
 // Tests that compare the behavior of the `UnixWaitIf` struct with the libc macros.
 #[cfg(all(test, unix))]
 mod libc_verification_tests {
     use super::*;
-    use libc::{WCOREDUMP, WEXITSTATUS, WIFEXITED, WIFSIGNALED, WTERMSIG};
+    use libc::{WCOREDUMP, WEXITSTATUS, WIFEXITED, WIFSIGNALED, WIFSTOPPED, WSTOPSIG, WTERMSIG};
 
     #[test]
     fn test_wifexited_true() {
@@ -321,4 +430,30 @@ mod libc_verification_tests {
         assert!(!WCOREDUMP(0x0000_0001));
         assert!(!WaitState::is_w_coredump(0x0000_0001));
     }
+
+    #[test]
+    fn test_wifstopped_true() {
+        assert!(WIFSTOPPED(0x0000_137F));
+        assert!(WaitState::is_w_stopped(0x0000_137F));
+    }
+
+    #[test]
+    fn test_wifstopped_false() {
+        assert!(!WIFSTOPPED(0x0000_0009));
+        assert!(!WaitState::is_w_stopped(0x0000_0009));
+    }
+
+    #[test]
+    fn test_wstopsig() {
+        assert_eq!(WSTOPSIG(0x0000_137F), 0x13);
+        assert_eq!(WaitState::w_stop_sig(0x0000_137F), 0x13);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_wifcontinued() {
+        assert!(libc::WIFCONTINUED(0x0000_FFFF));
+        assert!(WaitState::is_w_continued(0x0000_FFFF));
+        assert!(!libc::WIFSIGNALED(0x0000_FFFF));
+    }
 }
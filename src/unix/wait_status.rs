@@ -1,11 +1,14 @@
+use core::fmt::Display;
+
 use super::{ExitCode, Signal, WaitState};
+use crate::explain::Explanation;
 
 /// A Unix-like wait status.
 ///
 /// On Unix-like systems, processes can terminate with a combination of exit codes and signals;
 /// this struct encapsulates that information and can separate the exit code from the signal that
 /// caused the termination, or whether the process was stopped or continued.
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -53,6 +56,18 @@ impl WaitStatus {
         matches!(self.state(), WaitState::Signaled { .. })
     }
 
+    /// Returns `true` if the process was stopped by a signal.
+    #[must_use]
+    pub const fn is_stopped(&self) -> bool {
+        matches!(self.state(), WaitState::Stopped { .. })
+    }
+
+    /// Returns `true` if a stopped process was continued.
+    #[must_use]
+    pub const fn is_continued(&self) -> bool {
+        matches!(self.state(), WaitState::Continued)
+    }
+
     /// Returns the exit code if the process terminated normally, or `None` otherwise.
     #[must_use]
     pub const fn exit_code(&self) -> Option<ExitCode> {
@@ -70,17 +85,61 @@ impl WaitStatus {
             _ => None,
         }
     }
+
+    /// Returns the signal that stopped the process, or `None` if it was not stopped.
+    #[must_use]
+    pub const fn stop_signal(&self) -> Option<Signal> {
+        match self.state() {
+            WaitState::Stopped { signal } => Some(signal),
+            _ => None,
+        }
+    }
+
+    /// Returns a diagnostic explanation of the exit code or signal, if one is known.
+    #[must_use]
+    pub fn explain(&self) -> Option<Explanation> {
+        match self.state() {
+            WaitState::Exited { exit_code } => exit_code.explain(),
+            WaitState::Signaled { signal, .. } => signal.explain(),
+            _ => None,
+        }
+    }
+}
+
+/// Describes what happened to the process, i.e. `exited with code 1`.
+impl Display for WaitStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.state() {
+            WaitState::Exited { exit_code } => write!(f, "exited with code {exit_code}"),
+            WaitState::Signaled {
+                signal,
+                core_dump: false,
+            } => match signal.name() {
+                Some(name) => write!(f, "killed by signal {name} ({signal})"),
+                None => write!(f, "killed by signal {signal}"),
+            },
+            WaitState::Signaled {
+                signal,
+                core_dump: true,
+            } => match signal.name() {
+                Some(name) => write!(f, "terminated by {name} (core dumped)"),
+                None => write!(f, "terminated by signal {signal} (core dumped)"),
+            },
+            WaitState::Stopped { signal } => match signal.name() {
+                Some(name) => write!(f, "stopped by signal {name} ({signal})"),
+                None => write!(f, "stopped by signal {signal}"),
+            },
+            WaitState::Continued => f.write_str("continued"),
+            WaitState::Unsupported(status) => write!(f, "unrecognized wait status {status:#x}"),
+        }
+    }
 }
 
 #[cfg(all(unix, feature = "std"))]
 impl From<std::process::ExitStatus> for WaitStatus {
     fn from(status: std::process::ExitStatus) -> Self {
-        if let Some(code) = status.code() {
-            WaitStatus::from_raw(code)
-        } else {
-            use std::os::unix::process::ExitStatusExt;
-            WaitStatus::from_raw(status.into_raw())
-        }
+        use std::os::unix::process::ExitStatusExt;
+        WaitStatus::from_raw(status.into_raw())
     }
 }
 
@@ -91,3 +150,45 @@ impl From<WaitStatus> for std::process::ExitStatus {
         std::process::ExitStatus::from_raw(status.to_raw())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            WaitStatus::from_raw(0x0100).to_string(),
+            "exited with code 1"
+        );
+        assert_eq!(
+            WaitStatus::from_raw(9).to_string(),
+            "killed by signal SIGKILL (9)"
+        );
+        assert_eq!(
+            WaitStatus::from_raw(0x8B).to_string(),
+            "terminated by SIGSEGV (core dumped)"
+        );
+        assert_eq!(
+            WaitStatus::from_raw(0x40).to_string(),
+            "killed by signal 64"
+        );
+        assert_eq!(
+            WaitStatus::from_raw(0x147F).to_string(),
+            "stopped by signal SIGTSTP (20)"
+        );
+        assert_eq!(WaitStatus::from_raw(0xFFFF).to_string(), "continued");
+    }
+
+    #[test]
+    #[cfg(all(unix, feature = "std"))]
+    fn test_from_exit_status() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let status = WaitStatus::from(std::process::ExitStatus::from_raw(0x0100));
+        assert_eq!(status.exit_code(), Some(ExitCode::GENERAL_ERROR));
+
+        let status = WaitStatus::from(std::process::ExitStatus::from_raw(9));
+        assert_eq!(status.signal(), Some(Signal::KILL));
+    }
+}
@@ -0,0 +1,689 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use super::SoftwareException;
+use crate::explain::{self, Explanation};
+use crate::raw::RawExitCode;
+
+/// A Windows-specific exit code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct ExitCode(u32);
+
+impl ExitCode {
+    /// The program terminated successfully.
+    ///
+    /// Corresponds to exit code `0`.
+    ///
+    /// This is the universal success code.
+    pub const SUCCESS: Self = Self(0);
+
+    /// The program terminated with a general, unspecified error.
+    ///
+    /// Corresponds to exit code `1`.
+    ///
+    /// This is a common "catch-all" for general failures.
+    pub const GENERAL_ERROR: Self = Self(1);
+
+    /// The system cannot find the file specified.
+    ///
+    /// Corresponds to exit code `2` (`ERROR_FILE_NOT_FOUND`).
+    pub const FILE_NOT_FOUND: Self = Self(2);
+
+    /// The system cannot find the path specified.
+    ///
+    /// Corresponds to exit code `3` (`ERROR_PATH_NOT_FOUND`).
+    pub const PATH_NOT_FOUND: Self = Self(3);
+
+    /// Access is denied.
+    ///
+    /// Corresponds to exit code `5` (`ERROR_ACCESS_DENIED`).
+    pub const ACCESS_DENIED: Self = Self(5);
+
+    /// Not enough storage is available to process this command.
+    ///
+    /// Corresponds to exit code `8` (`ERROR_NOT_ENOUGH_MEMORY`).
+    pub const NOT_ENOUGH_MEMORY: Self = Self(8);
+
+    /// The parameter is incorrect.
+    ///
+    /// Corresponds to exit code `87` (`ERROR_INVALID_PARAMETER`).
+    pub const INVALID_PARAMETER: Self = Self(87);
+
+    /// The pipe has been ended.
+    ///
+    /// Corresponds to exit code `109` (`ERROR_BROKEN_PIPE`).
+    pub const BROKEN_PIPE: Self = Self(109);
+
+    /// The service reported a service-specific error.
+    ///
+    /// Corresponds to exit code `1066` (`ERROR_SERVICE_SPECIFIC_ERROR`).
+    ///
+    /// The actual error is reported separately; see [`ServiceExitCode`](super::ServiceExitCode).
+    pub const SERVICE_SPECIFIC_ERROR: Self = Self(1066);
+
+    /// The user cancelled the installation.
+    ///
+    /// Corresponds to exit code `1602` (`ERROR_INSTALL_USEREXIT`).
+    pub const INSTALL_USER_EXIT: Self = Self(1602);
+
+    /// A fatal error occurred during installation.
+    ///
+    /// Corresponds to exit code `1603` (`ERROR_INSTALL_FAILURE`).
+    pub const INSTALL_FAILURE: Self = Self(1603);
+
+    /// The product is not installed, i.e. when uninstalling it.
+    ///
+    /// Corresponds to exit code `1605` (`ERROR_UNKNOWN_PRODUCT`).
+    pub const UNKNOWN_PRODUCT: Self = Self(1605);
+
+    /// The product is already uninstalled.
+    ///
+    /// Corresponds to exit code `1614` (`ERROR_PRODUCT_UNINSTALLED`).
+    pub const PRODUCT_UNINSTALLED: Self = Self(1614);
+
+    /// Another installation is already in progress.
+    ///
+    /// Corresponds to exit code `1618` (`ERROR_INSTALL_ALREADY_RUNNING`).
+    pub const INSTALL_ALREADY_RUNNING: Self = Self(1618);
+
+    /// The installation succeeded, and a reboot was initiated to complete it.
+    ///
+    /// Corresponds to exit code `1641` (`ERROR_SUCCESS_REBOOT_INITIATED`).
+    pub const SUCCESS_REBOOT_INITIATED: Self = Self(1641);
+
+    /// The installation succeeded, but a reboot is required to complete it.
+    ///
+    /// Corresponds to exit code `3010` (`ERROR_SUCCESS_REBOOT_REQUIRED`).
+    pub const SUCCESS_REBOOT_REQUIRED: Self = Self(3010);
+
+    /// The program is not recognized as a command, operable program, or batch file.
+    ///
+    /// Corresponds to exit code `9009`.
+    ///
+    /// This is a common code returned by `cmd.exe` when a command cannot be found or executed.
+    pub const COMMAND_NOT_RECOGNIZED: Self = Self(9009);
+
+    /// The process has not terminated yet, or terminated with the same code.
+    ///
+    /// Corresponds to exit code `259` (`STILL_ACTIVE`, also known as `STATUS_PENDING`).
+    ///
+    /// `GetExitCodeProcess` reports this code for processes that are still running, so a process
+    /// that really exits with `259` is indistinguishable from a running one; see
+    /// [`ProcessState`](super::ProcessState).
+    pub const STILL_ACTIVE: Self = Self(259);
+
+    /// The program terminated as a result of a CTRL+C or Ctrl+Break signal.
+    ///
+    /// Cooresponds to exit code `0xC000_013A`.
+    pub const TERMINATED_BY_CTRL_C: Self = Self(0xC000_013A);
+
+    /// The program was terminate due to an access violation.
+    ///
+    /// Corresponds to exit code `0xC000_0005`.
+    pub const ACCESS_VIOLATION: Self = Self(0xC000_0005);
+
+    /// The program terminated due to a stack oveflow.
+    ///
+    /// Corresponds to exit code `0xC000_00FD`.
+    pub const STACK_OVERFLOW: Self = Self(0xC000_00FD);
+
+    /// The program failed fast, i.e. by calling `abort()` or `__fastfail`.
+    ///
+    /// Corresponds to exit code `0xC000_0409` (`STATUS_STACK_BUFFER_OVERRUN`).
+    ///
+    /// Despite the name, this code is used for every fail-fast termination, including
+    /// deliberate aborts; see [`FailFast`](super::FailFast) to tell them apart.
+    pub const STACK_BUFFER_OVERRUN: Self = Self(0xC000_0409);
+
+    /// A .NET exception was not caught.
+    ///
+    /// Corresponds to exit code `0xE043_4352` (`EXCEPTION_COMPLUS`).
+    pub const CLR_EXCEPTION: Self = Self(0xE043_4352);
+
+    /// The .NET runtime encountered a fatal internal error, or `Environment.FailFast` was called.
+    ///
+    /// Corresponds to exit code `0x8013_1506` (`COR_E_EXECUTIONENGINE`).
+    pub const CLR_EXECUTION_ENGINE_ERROR: Self = Self(0x8013_1506);
+
+    /// A C++ exception was not caught.
+    ///
+    /// Corresponds to exit code `0xE06D_7363` (`EH_EXCEPTION_NUMBER`, `"msc"` in ASCII).
+    pub const CPP_EXCEPTION: Self = Self(0xE06D_7363);
+
+    /// A Delphi exception was not caught.
+    ///
+    /// Corresponds to exit code `0x0EED_FADE` (`cDelphiException`).
+    pub const DELPHI_EXCEPTION: Self = Self(0x0EED_FADE);
+
+    /// The program attempted to execute an invalid instruction.
+    ///
+    /// Corresponds to exit code `0xC000_001D` (`STATUS_ILLEGAL_INSTRUCTION`).
+    pub const ILLEGAL_INSTRUCTION: Self = Self(0xC000_001D);
+
+    /// The program attempted to execute an instruction not allowed in the current mode.
+    ///
+    /// Corresponds to exit code `0xC000_0096` (`STATUS_PRIVILEGED_INSTRUCTION`).
+    pub const PRIVILEGED_INSTRUCTION: Self = Self(0xC000_0096);
+
+    /// The program attempted to divide an integer by zero.
+    ///
+    /// Corresponds to exit code `0xC000_0094` (`STATUS_INTEGER_DIVIDE_BY_ZERO`).
+    pub const INTEGER_DIVIDE_BY_ZERO: Self = Self(0xC000_0094);
+
+    /// The result of an integer operation overflowed.
+    ///
+    /// Corresponds to exit code `0xC000_0095` (`STATUS_INTEGER_OVERFLOW`).
+    pub const INTEGER_OVERFLOW: Self = Self(0xC000_0095);
+
+    /// The program attempted to divide a floating-point value by zero.
+    ///
+    /// Corresponds to exit code `0xC000_008E` (`STATUS_FLOAT_DIVIDE_BY_ZERO`).
+    ///
+    /// Floating-point exceptions are masked by default, so this is rare in practice.
+    pub const FLOAT_DIVIDE_BY_ZERO: Self = Self(0xC000_008E);
+
+    /// A page of memory could not be read in, i.e. from a memory-mapped file on a lost volume.
+    ///
+    /// Corresponds to exit code `0xC000_0006` (`STATUS_IN_PAGE_ERROR`).
+    pub const IN_PAGE_ERROR: Self = Self(0xC000_0006);
+
+    /// The program performed a misaligned memory access.
+    ///
+    /// Corresponds to exit code `0x8000_0002` (`STATUS_DATATYPE_MISALIGNMENT`).
+    pub const DATATYPE_MISALIGNMENT: Self = Self(0x8000_0002);
+
+    /// The program hit a breakpoint with no debugger attached.
+    ///
+    /// Corresponds to exit code `0x8000_0003` (`STATUS_BREAKPOINT`).
+    pub const BREAKPOINT: Self = Self(0x8000_0003);
+
+    /// Creates a new `ExitCode` from the underlying `u32` code.
+    #[must_use]
+    pub const fn from_raw(code: u32) -> Self {
+        Self(code)
+    }
+
+    /// Returns the underlying `u32` code.
+    #[must_use]
+    pub const fn to_raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if the exit code represents a successful termination.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.0 == Self::SUCCESS.0
+    }
+
+    /// Returns `true` if the exit code represents a failure termination.
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        !self.is_success()
+    }
+
+    /// Returns `true` if the exit code is [`ExitCode::STILL_ACTIVE`].
+    ///
+    /// Such an exit code, when read from `GetExitCodeProcess`, does not prove that the process
+    /// has terminated.
+    #[must_use]
+    pub const fn is_still_active(&self) -> bool {
+        self.0 == Self::STILL_ACTIVE.0
+    }
+
+    /// Returns `true` if the exit code is [`ExitCode::STACK_BUFFER_OVERRUN`].
+    #[must_use]
+    pub const fn is_fail_fast(&self) -> bool {
+        self.0 == Self::STACK_BUFFER_OVERRUN.0
+    }
+
+    /// Returns the language runtime exception the exit code represents, if any.
+    ///
+    /// See [`SoftwareException`] for details.
+    #[must_use]
+    pub const fn software_exception(&self) -> Option<SoftwareException> {
+        SoftwareException::from_exit_code(*self)
+    }
+
+    /// Returns the symbolic Windows name of the exit code, if known.
+    ///
+    /// For example, `0xC000_0005` is `STATUS_ACCESS_VIOLATION` and `5` is `ERROR_ACCESS_DENIED`.
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        NAMES
+            .iter()
+            .find(|(code, _)| code == self)
+            .map(|(_, name)| *name)
+    }
+
+    /// Returns the exit code with the given symbolic Windows name, if known.
+    ///
+    /// The comparison is ASCII case-insensitive.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(code, _)| *code)
+    }
+
+    /// Returns a diagnostic explanation of the exit code, if one is known.
+    #[must_use]
+    pub fn explain(&self) -> Option<Explanation> {
+        explain::windows_exit_code(*self)
+    }
+
+    /// Returns a value that displays the exit code in the given format.
+    ///
+    /// Every format can be parsed back using [`ExitCode::from_str`].
+    #[must_use]
+    pub const fn format(&self, format: ExitCodeFormat) -> FormattedExitCode {
+        FormattedExitCode {
+            code: *self,
+            format,
+        }
+    }
+}
+
+/// Symbolic names of known exit codes.
+///
+/// Codes without a well-known Windows name (such as `1` or `9009`) are intentionally omitted.
+const NAMES: &[(ExitCode, &str)] = &[
+    (ExitCode::SUCCESS, "ERROR_SUCCESS"),
+    (ExitCode::FILE_NOT_FOUND, "ERROR_FILE_NOT_FOUND"),
+    (ExitCode::PATH_NOT_FOUND, "ERROR_PATH_NOT_FOUND"),
+    (ExitCode::ACCESS_DENIED, "ERROR_ACCESS_DENIED"),
+    (ExitCode::NOT_ENOUGH_MEMORY, "ERROR_NOT_ENOUGH_MEMORY"),
+    (ExitCode::INVALID_PARAMETER, "ERROR_INVALID_PARAMETER"),
+    (ExitCode::BROKEN_PIPE, "ERROR_BROKEN_PIPE"),
+    (ExitCode::STILL_ACTIVE, "STILL_ACTIVE"),
+    (
+        ExitCode::SERVICE_SPECIFIC_ERROR,
+        "ERROR_SERVICE_SPECIFIC_ERROR",
+    ),
+    (ExitCode::INSTALL_USER_EXIT, "ERROR_INSTALL_USEREXIT"),
+    (ExitCode::INSTALL_FAILURE, "ERROR_INSTALL_FAILURE"),
+    (ExitCode::UNKNOWN_PRODUCT, "ERROR_UNKNOWN_PRODUCT"),
+    (ExitCode::PRODUCT_UNINSTALLED, "ERROR_PRODUCT_UNINSTALLED"),
+    (
+        ExitCode::INSTALL_ALREADY_RUNNING,
+        "ERROR_INSTALL_ALREADY_RUNNING",
+    ),
+    (
+        ExitCode::SUCCESS_REBOOT_INITIATED,
+        "ERROR_SUCCESS_REBOOT_INITIATED",
+    ),
+    (
+        ExitCode::SUCCESS_REBOOT_REQUIRED,
+        "ERROR_SUCCESS_REBOOT_REQUIRED",
+    ),
+    (ExitCode::TERMINATED_BY_CTRL_C, "STATUS_CONTROL_C_EXIT"),
+    (ExitCode::ACCESS_VIOLATION, "STATUS_ACCESS_VIOLATION"),
+    (ExitCode::STACK_OVERFLOW, "STATUS_STACK_OVERFLOW"),
+    (
+        ExitCode::STACK_BUFFER_OVERRUN,
+        "STATUS_STACK_BUFFER_OVERRUN",
+    ),
+    (ExitCode::CLR_EXCEPTION, "EXCEPTION_COMPLUS"),
+    (
+        ExitCode::CLR_EXECUTION_ENGINE_ERROR,
+        "COR_E_EXECUTIONENGINE",
+    ),
+    (ExitCode::CPP_EXCEPTION, "EH_EXCEPTION_NUMBER"),
+    (ExitCode::DELPHI_EXCEPTION, "cDelphiException"),
+    (ExitCode::ILLEGAL_INSTRUCTION, "STATUS_ILLEGAL_INSTRUCTION"),
+    (
+        ExitCode::PRIVILEGED_INSTRUCTION,
+        "STATUS_PRIVILEGED_INSTRUCTION",
+    ),
+    (
+        ExitCode::INTEGER_DIVIDE_BY_ZERO,
+        "STATUS_INTEGER_DIVIDE_BY_ZERO",
+    ),
+    (ExitCode::INTEGER_OVERFLOW, "STATUS_INTEGER_OVERFLOW"),
+    (
+        ExitCode::FLOAT_DIVIDE_BY_ZERO,
+        "STATUS_FLOAT_DIVIDE_BY_ZERO",
+    ),
+    (ExitCode::IN_PAGE_ERROR, "STATUS_IN_PAGE_ERROR"),
+    (
+        ExitCode::DATATYPE_MISALIGNMENT,
+        "STATUS_DATATYPE_MISALIGNMENT",
+    ),
+    (ExitCode::BREAKPOINT, "STATUS_BREAKPOINT"),
+];
+
+/// Styles in which an [`ExitCode`] can be formatted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ExitCodeFormat {
+    /// Unsigned decimal, i.e. `3221225477`.
+    ///
+    /// This is the same as the [`Display`] implementation of [`ExitCode`].
+    #[default]
+    Unsigned,
+
+    /// Signed decimal, i.e. `-1073741819`.
+    ///
+    /// This is how PowerShell reports `$LASTEXITCODE`.
+    Signed,
+
+    /// Hexadecimal, i.e. `0xC0000005`.
+    ///
+    /// This is how debuggers and most documentation report `NTSTATUS` values.
+    Hex,
+
+    /// Symbolic name, i.e. `STATUS_ACCESS_VIOLATION`.
+    ///
+    /// Falls back to [`ExitCodeFormat::Hex`] if the exit code has no known name.
+    Name,
+}
+
+/// Displays an [`ExitCode`] in a specific [`ExitCodeFormat`].
+///
+/// Created by [`ExitCode::format`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FormattedExitCode {
+    code: ExitCode,
+    format: ExitCodeFormat,
+}
+
+impl Display for FormattedExitCode {
+    #[allow(clippy::cast_possible_wrap)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.format {
+            ExitCodeFormat::Unsigned => write!(f, "{}", self.code.0),
+            ExitCodeFormat::Signed => write!(f, "{}", self.code.0 as i32),
+            ExitCodeFormat::Hex => write!(f, "0x{:08X}", self.code.0),
+            ExitCodeFormat::Name => match self.code.name() {
+                Some(name) => f.write_str(name),
+                None => write!(f, "0x{:08X}", self.code.0),
+            },
+        }
+    }
+}
+
+/// An error which can be returned when parsing an [`ExitCode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseExitCodeError {
+    kind: ParseErrorKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    Empty,
+    InvalidDigit,
+    OutOfRange,
+    UnknownName,
+}
+
+impl Display for ParseExitCodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self.kind {
+            ParseErrorKind::Empty => "cannot parse exit code from empty string",
+            ParseErrorKind::InvalidDigit => "invalid digit found in exit code",
+            ParseErrorKind::OutOfRange => "exit code does not fit in 32 bits",
+            ParseErrorKind::UnknownName => "unknown exit code name",
+        })
+    }
+}
+
+impl core::error::Error for ParseExitCodeError {}
+
+impl From<core::num::ParseIntError> for ParseExitCodeError {
+    fn from(error: core::num::ParseIntError) -> Self {
+        use core::num::IntErrorKind;
+        let kind = match error.kind() {
+            IntErrorKind::Empty => ParseErrorKind::Empty,
+            IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ParseErrorKind::OutOfRange,
+            _ => ParseErrorKind::InvalidDigit,
+        };
+        Self { kind }
+    }
+}
+
+impl FromStr for ExitCode {
+    type Err = ParseExitCodeError;
+
+    /// Parses an exit code from any of the forms Windows tooling reports them in.
+    ///
+    /// Accepts unsigned decimal (`3221225477`), signed decimal (`-1073741819`), hexadecimal
+    /// (`0xC0000005`), or a known symbolic name (`STATUS_ACCESS_VIOLATION`).
+    #[allow(clippy::cast_sign_loss)]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseExitCodeError {
+                kind: ParseErrorKind::Empty,
+            });
+        }
+        if let Some(hex) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+            // `from_str_radix` accepts a leading sign, which has no place after `0x`.
+            if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(ParseExitCodeError {
+                    kind: ParseErrorKind::InvalidDigit,
+                });
+            }
+            return Ok(Self(u32::from_str_radix(hex, 16)?));
+        }
+        if s.starts_with('-') {
+            return Ok(Self(s.parse::<i32>()? as u32));
+        }
+        if s.starts_with(|c: char| c.is_ascii_digit() || c == '+') {
+            return Ok(Self(s.parse::<u32>()?));
+        }
+        Self::from_name(s).ok_or(ParseExitCodeError {
+            kind: ParseErrorKind::UnknownName,
+        })
+    }
+}
+
+impl RawExitCode for ExitCode {
+    type Code = u32;
+
+    fn from_raw(code: Self::Code) -> Self {
+        ExitCode::from_raw(code)
+    }
+
+    fn to_raw(&self) -> Self::Code {
+        self.to_raw()
+    }
+}
+
+impl From<u32> for ExitCode {
+    fn from(code: u32) -> Self {
+        ExitCode::from_raw(code)
+    }
+}
+
+impl Display for ExitCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(all(windows, feature = "std"))]
+impl From<std::process::ExitStatus> for ExitCode {
+    fn from(status: std::process::ExitStatus) -> ExitCode {
+        // Exit codes are `u32`s, which `code()` reports as `i32`s, i.e. a crash is negative.
+        ExitCode::from_raw(
+            status
+                .code()
+                .expect("cannot fail on Windows")
+                .cast_unsigned(),
+        )
+    }
+}
+
+#[cfg(all(windows, feature = "std"))]
+impl From<ExitCode> for std::process::ExitStatus {
+    fn from(code: ExitCode) -> Self {
+        use std::os::windows::process::ExitStatusExt;
+        std::process::ExitStatus::from_raw(code.to_raw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_raw() {
+        assert_eq!(ExitCode::from_raw(0).to_raw(), 0);
+    }
+
+    #[test]
+    fn test_is_success() {
+        assert!(ExitCode::SUCCESS.is_success());
+    }
+
+    #[test]
+    fn test_is_failure() {
+        assert!(ExitCode::GENERAL_ERROR.is_failure());
+    }
+
+    #[test]
+    fn test_is_still_active() {
+        assert!(ExitCode::from_raw(259).is_still_active());
+        assert!(!ExitCode::SUCCESS.is_still_active());
+    }
+
+    #[test]
+    fn test_from_u32() {
+        let code: ExitCode = 1.into();
+        assert_eq!(code.to_raw(), 1);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let expected = ExitCode::ACCESS_VIOLATION;
+        assert_eq!("3221225477".parse(), Ok(expected));
+        assert_eq!("-1073741819".parse(), Ok(expected));
+        assert_eq!("0xC0000005".parse(), Ok(expected));
+        assert_eq!("0xc0000005".parse(), Ok(expected));
+        assert_eq!("STATUS_ACCESS_VIOLATION".parse(), Ok(expected));
+        assert_eq!("ERROR_ACCESS_DENIED".parse(), Ok(ExitCode::ACCESS_DENIED));
+        assert_eq!(
+            "ERROR_SUCCESS_REBOOT_REQUIRED".parse(),
+            Ok(ExitCode::SUCCESS_REBOOT_REQUIRED)
+        );
+        assert_eq!(
+            ExitCode::INSTALL_ALREADY_RUNNING.name(),
+            Some("ERROR_INSTALL_ALREADY_RUNNING")
+        );
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        assert_eq!(
+            "".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::Empty
+        );
+        assert_eq!(
+            "0xZZ".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::InvalidDigit
+        );
+        for signed in ["0x+5", "0x-5"] {
+            assert_eq!(
+                signed.parse::<ExitCode>().unwrap_err().kind,
+                ParseErrorKind::InvalidDigit
+            );
+        }
+        assert_eq!(
+            "0x".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::Empty
+        );
+        assert_eq!(
+            "4294967296".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::OutOfRange
+        );
+        assert_eq!(
+            "-2147483649".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::OutOfRange
+        );
+        assert_eq!(
+            "STATUS_NOT_A_THING".parse::<ExitCode>().unwrap_err().kind,
+            ParseErrorKind::UnknownName
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let code = ExitCode::ACCESS_VIOLATION;
+        assert_eq!(
+            code.format(ExitCodeFormat::Unsigned).to_string(),
+            "3221225477"
+        );
+        assert_eq!(
+            code.format(ExitCodeFormat::Signed).to_string(),
+            "-1073741819"
+        );
+        assert_eq!(code.format(ExitCodeFormat::Hex).to_string(), "0xC0000005");
+        assert_eq!(
+            code.format(ExitCodeFormat::Name).to_string(),
+            "STATUS_ACCESS_VIOLATION"
+        );
+        assert_eq!(
+            ExitCode::from_raw(42)
+                .format(ExitCodeFormat::Name)
+                .to_string(),
+            "0x0000002A"
+        );
+    }
+
+    #[test]
+    fn test_format_round_trip() {
+        let formats = [
+            ExitCodeFormat::Unsigned,
+            ExitCodeFormat::Signed,
+            ExitCodeFormat::Hex,
+            ExitCodeFormat::Name,
+        ];
+        for code in [0, 1, 5, 259, 0x7FFF_FFFF, 0xC000_0005, u32::MAX] {
+            let code = ExitCode::from_raw(code);
+            for format in formats {
+                let text = code.format(format).to_string();
+                assert_eq!(text.parse(), Ok(code), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", windows))]
+    fn test_from_exit_status() {
+        use std::os::windows::process::ExitStatusExt;
+        use std::process::ExitStatus;
+
+        // Simulate a successful exit status
+        let success_status = ExitStatus::from_raw(0);
+        let success_code: ExitCode = success_status.into();
+        assert!(success_code.is_success());
+        assert_eq!(success_code.to_raw(), 0);
+
+        // Simulate a failure exit status
+        let failure_status = ExitStatus::from_raw(1);
+        let failure_code: ExitCode = failure_status.into();
+        assert!(failure_code.is_failure());
+        assert_eq!(failure_code.to_raw(), 1);
+
+        // Simulate a crash, which `code()` reports as a negative number
+        let crash_code: ExitCode = ExitStatus::from_raw(0xC000_0005).into();
+        assert_eq!(crash_code, ExitCode::ACCESS_VIOLATION);
+    }
+}
+
+#[cfg(all(test, windows, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn test_serde() {
+        let code = ExitCode::SUCCESS;
+        let serialized = serde_json::to_string(&code).unwrap();
+        let deserialized: ExitCode = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(code, deserialized);
+    }
+}
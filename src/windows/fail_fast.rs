@@ -0,0 +1,399 @@
+use core::fmt::Display;
+
+use super::ExitCode;
+
+/// A `FAST_FAIL_*` subcode passed to `__fastfail`.
+///
+/// When a process fails fast, it terminates with [`ExitCode::STACK_BUFFER_OVERRUN`], and the
+/// subcode is recorded as the first parameter of the exception record (available from crash
+/// dumps and Windows Error Reporting, but not from the exit code itself).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct FailFastCode(u32);
+
+impl FailFastCode {
+    /// A legacy `/GS` stack cookie violation (`FAST_FAIL_LEGACY_GS_VIOLATION`).
+    pub const LEGACY_GS_VIOLATION: Self = Self(0);
+
+    /// A corrupt virtual table guard (`FAST_FAIL_VTGUARD_CHECK_FAILURE`).
+    pub const VTGUARD_CHECK_FAILURE: Self = Self(1);
+
+    /// A `/GS` stack cookie was overwritten (`FAST_FAIL_STACK_COOKIE_CHECK_FAILURE`).
+    pub const STACK_COOKIE_CHECK_FAILURE: Self = Self(2);
+
+    /// A doubly-linked list entry was corrupt (`FAST_FAIL_CORRUPT_LIST_ENTRY`).
+    pub const CORRUPT_LIST_ENTRY: Self = Self(3);
+
+    /// The stack pointer was outside the stack (`FAST_FAIL_INCORRECT_STACK`).
+    pub const INCORRECT_STACK: Self = Self(4);
+
+    /// The C runtime invalid parameter handler was invoked (`FAST_FAIL_INVALID_ARG`).
+    pub const INVALID_ARG: Self = Self(5);
+
+    /// The `/GS` stack cookie could not be initialized (`FAST_FAIL_GS_COOKIE_INIT`).
+    pub const GS_COOKIE_INIT: Self = Self(6);
+
+    /// The program deliberately terminated (`FAST_FAIL_FATAL_APP_EXIT`).
+    ///
+    /// Raised by `abort()` in the C runtime and by [`std::process::abort`] in Rust.
+    pub const FATAL_APP_EXIT: Self = Self(7);
+
+    /// An array index was out of bounds (`FAST_FAIL_RANGE_CHECK_FAILURE`).
+    pub const RANGE_CHECK_FAILURE: Self = Self(8);
+
+    /// A registry access was unsafe (`FAST_FAIL_UNSAFE_REGISTRY_ACCESS`).
+    pub const UNSAFE_REGISTRY_ACCESS: Self = Self(9);
+
+    /// Control Flow Guard rejected an indirect call (`FAST_FAIL_GUARD_ICALL_CHECK_FAILURE`).
+    pub const GUARD_ICALL_CHECK_FAILURE: Self = Self(10);
+
+    /// Control Flow Guard rejected a write (`FAST_FAIL_GUARD_WRITE_CHECK_FAILURE`).
+    pub const GUARD_WRITE_CHECK_FAILURE: Self = Self(11);
+
+    /// A fiber switch was invalid (`FAST_FAIL_INVALID_FIBER_SWITCH`).
+    pub const INVALID_FIBER_SWITCH: Self = Self(12);
+
+    /// A thread context was invalid (`FAST_FAIL_INVALID_SET_OF_CONTEXT`).
+    pub const INVALID_SET_OF_CONTEXT: Self = Self(13);
+
+    /// A reference count under- or overflowed (`FAST_FAIL_INVALID_REFERENCE_COUNT`).
+    pub const INVALID_REFERENCE_COUNT: Self = Self(14);
+
+    /// A `longjmp` buffer was invalid (`FAST_FAIL_INVALID_JUMP_BUFFER`).
+    pub const INVALID_JUMP_BUFFER: Self = Self(18);
+
+    /// Read-only loader data was modified (`FAST_FAIL_MRDATA_MODIFIED`).
+    pub const MRDATA_MODIFIED: Self = Self(19);
+
+    /// An exception handler chain was corrupt (`FAST_FAIL_INVALID_EXCEPTION_CHAIN`).
+    pub const INVALID_EXCEPTION_CHAIN: Self = Self(21);
+
+    /// A buffer access was out of bounds (`FAST_FAIL_INVALID_BUFFER_ACCESS`).
+    pub const INVALID_BUFFER_ACCESS: Self = Self(28);
+
+    /// A balanced tree was corrupt (`FAST_FAIL_INVALID_BALANCED_TREE`).
+    pub const INVALID_BALANCED_TREE: Self = Self(29);
+
+    /// Control Flow Guard rejected a jump table target (`FAST_FAIL_GUARD_JUMPTABLE`).
+    pub const GUARD_JUMPTABLE: Self = Self(37);
+
+    /// A `longjmp` target was invalid (`FAST_FAIL_INVALID_LONGJUMP_TARGET`).
+    pub const INVALID_LONGJUMP_TARGET: Self = Self(38);
+
+    /// A return address did not match the shadow stack (`FAST_FAIL_GUARD_SS_FAILURE`).
+    pub const GUARD_SS_FAILURE: Self = Self(44);
+
+    /// Heap metadata was corrupt (`FAST_FAIL_HEAP_METADATA_CORRUPTION`).
+    pub const HEAP_METADATA_CORRUPTION: Self = Self(50);
+
+    /// Creates a new `FailFastCode` from the underlying `u32` subcode.
+    #[must_use]
+    pub const fn from_raw(code: u32) -> Self {
+        Self(code)
+    }
+
+    /// Returns the underlying `u32` subcode.
+    #[must_use]
+    pub const fn to_raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the symbolic `FAST_FAIL_*` name of the subcode, if known.
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        FAIL_FAST_CODES
+            .iter()
+            .find(|(code, _, _)| code == self)
+            .map(|(_, name, _)| *name)
+    }
+
+    /// Returns the kind of failure the subcode indicates.
+    #[must_use]
+    pub fn kind(&self) -> FailFastKind {
+        FAIL_FAST_CODES
+            .iter()
+            .find(|(code, _, _)| code == self)
+            .map_or(FailFastKind::Other, |(_, _, kind)| *kind)
+    }
+}
+
+impl From<u32> for FailFastCode {
+    fn from(code: u32) -> Self {
+        FailFastCode::from_raw(code)
+    }
+}
+
+impl Display for FailFastCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => self.0.fmt(f),
+        }
+    }
+}
+
+/// Known subcodes, their names, and what kind of failure they indicate.
+const FAIL_FAST_CODES: &[(FailFastCode, &str, FailFastKind)] = &[
+    (
+        FailFastCode::LEGACY_GS_VIOLATION,
+        "FAST_FAIL_LEGACY_GS_VIOLATION",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::VTGUARD_CHECK_FAILURE,
+        "FAST_FAIL_VTGUARD_CHECK_FAILURE",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::STACK_COOKIE_CHECK_FAILURE,
+        "FAST_FAIL_STACK_COOKIE_CHECK_FAILURE",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::CORRUPT_LIST_ENTRY,
+        "FAST_FAIL_CORRUPT_LIST_ENTRY",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INCORRECT_STACK,
+        "FAST_FAIL_INCORRECT_STACK",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INVALID_ARG,
+        "FAST_FAIL_INVALID_ARG",
+        FailFastKind::InvalidArgument,
+    ),
+    (
+        FailFastCode::GS_COOKIE_INIT,
+        "FAST_FAIL_GS_COOKIE_INIT",
+        FailFastKind::Other,
+    ),
+    (
+        FailFastCode::FATAL_APP_EXIT,
+        "FAST_FAIL_FATAL_APP_EXIT",
+        FailFastKind::Abort,
+    ),
+    (
+        FailFastCode::RANGE_CHECK_FAILURE,
+        "FAST_FAIL_RANGE_CHECK_FAILURE",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::UNSAFE_REGISTRY_ACCESS,
+        "FAST_FAIL_UNSAFE_REGISTRY_ACCESS",
+        FailFastKind::Other,
+    ),
+    (
+        FailFastCode::GUARD_ICALL_CHECK_FAILURE,
+        "FAST_FAIL_GUARD_ICALL_CHECK_FAILURE",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::GUARD_WRITE_CHECK_FAILURE,
+        "FAST_FAIL_GUARD_WRITE_CHECK_FAILURE",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::INVALID_FIBER_SWITCH,
+        "FAST_FAIL_INVALID_FIBER_SWITCH",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::INVALID_SET_OF_CONTEXT,
+        "FAST_FAIL_INVALID_SET_OF_CONTEXT",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::INVALID_REFERENCE_COUNT,
+        "FAST_FAIL_INVALID_REFERENCE_COUNT",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INVALID_JUMP_BUFFER,
+        "FAST_FAIL_INVALID_JUMP_BUFFER",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::MRDATA_MODIFIED,
+        "FAST_FAIL_MRDATA_MODIFIED",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INVALID_EXCEPTION_CHAIN,
+        "FAST_FAIL_INVALID_EXCEPTION_CHAIN",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INVALID_BUFFER_ACCESS,
+        "FAST_FAIL_INVALID_BUFFER_ACCESS",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::INVALID_BALANCED_TREE,
+        "FAST_FAIL_INVALID_BALANCED_TREE",
+        FailFastKind::MemoryCorruption,
+    ),
+    (
+        FailFastCode::GUARD_JUMPTABLE,
+        "FAST_FAIL_GUARD_JUMPTABLE",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::INVALID_LONGJUMP_TARGET,
+        "FAST_FAIL_INVALID_LONGJUMP_TARGET",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::GUARD_SS_FAILURE,
+        "FAST_FAIL_GUARD_SS_FAILURE",
+        FailFastKind::ControlFlowViolation,
+    ),
+    (
+        FailFastCode::HEAP_METADATA_CORRUPTION,
+        "FAST_FAIL_HEAP_METADATA_CORRUPTION",
+        FailFastKind::MemoryCorruption,
+    ),
+];
+
+/// The kind of failure a [`FailFastCode`] indicates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FailFastKind {
+    /// The program deliberately terminated, i.e. by calling `abort()`.
+    Abort,
+
+    /// The C runtime rejected an invalid argument to a library function.
+    InvalidArgument,
+
+    /// A runtime check detected corrupted memory, such as an overwritten stack cookie.
+    MemoryCorruption,
+
+    /// A control flow integrity check (i.e. Control Flow Guard) rejected a branch target.
+    ControlFlowViolation,
+
+    /// Any other failure, or a subcode that is not recognized.
+    Other,
+}
+
+/// A decoded fail-fast termination.
+///
+/// Created from an exit code of [`ExitCode::STACK_BUFFER_OVERRUN`], and optionally the first
+/// exception parameter, which holds the [`FailFastCode`]:
+///
+/// ```rust
+/// use proc_result::windows::{ExitCode, FailFast, FailFastKind};
+///
+/// let fail_fast = FailFast::from_exit_code(ExitCode::STACK_BUFFER_OVERRUN, Some(7)).unwrap();
+/// assert_eq!(fail_fast.kind(), Some(FailFastKind::Abort));
+/// assert!(fail_fast.is_intentional());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FailFast {
+    code: Option<FailFastCode>,
+}
+
+impl FailFast {
+    /// Decodes a fail-fast termination from an exit code and optional exception parameter.
+    ///
+    /// Returns `None` if the exit code is not [`ExitCode::STACK_BUFFER_OVERRUN`].
+    #[must_use]
+    pub const fn from_exit_code(code: ExitCode, parameter: Option<u32>) -> Option<Self> {
+        if code.to_raw() != ExitCode::STACK_BUFFER_OVERRUN.to_raw() {
+            return None;
+        }
+        Some(Self {
+            code: match parameter {
+                Some(parameter) => Some(FailFastCode::from_raw(parameter)),
+                None => None,
+            },
+        })
+    }
+
+    /// Returns the subcode, or `None` if it was not provided.
+    #[must_use]
+    pub const fn code(&self) -> Option<FailFastCode> {
+        self.code
+    }
+
+    /// Returns the kind of failure, or `None` if the subcode was not provided.
+    ///
+    /// Without a subcode, an intentional abort cannot be told apart from memory corruption.
+    #[must_use]
+    pub fn kind(&self) -> Option<FailFastKind> {
+        self.code.map(|code| code.kind())
+    }
+
+    /// Returns `true` if the program is known to have terminated deliberately.
+    #[must_use]
+    pub fn is_intentional(&self) -> bool {
+        self.kind() == Some(FailFastKind::Abort)
+    }
+
+    /// Returns `true` if the program is known to have detected corrupted memory or control flow.
+    #[must_use]
+    pub fn is_corruption(&self) -> bool {
+        matches!(
+            self.kind(),
+            Some(FailFastKind::MemoryCorruption | FailFastKind::ControlFlowViolation)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_exit_code() {
+        assert_eq!(
+            FailFast::from_exit_code(ExitCode::ACCESS_VIOLATION, Some(7)),
+            None
+        );
+
+        let fail_fast = FailFast::from_exit_code(ExitCode::STACK_BUFFER_OVERRUN, None).unwrap();
+        assert_eq!(fail_fast.code(), None);
+        assert_eq!(fail_fast.kind(), None);
+        assert!(!fail_fast.is_intentional());
+        assert!(!fail_fast.is_corruption());
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(FailFastCode::FATAL_APP_EXIT.kind(), FailFastKind::Abort);
+        assert_eq!(
+            FailFastCode::STACK_COOKIE_CHECK_FAILURE.kind(),
+            FailFastKind::MemoryCorruption
+        );
+        assert_eq!(
+            FailFastCode::GUARD_ICALL_CHECK_FAILURE.kind(),
+            FailFastKind::ControlFlowViolation
+        );
+        assert_eq!(
+            FailFastCode::INVALID_ARG.kind(),
+            FailFastKind::InvalidArgument
+        );
+        assert_eq!(FailFastCode::from_raw(1000).kind(), FailFastKind::Other);
+    }
+
+    #[test]
+    fn test_is_corruption() {
+        let fail_fast = FailFast::from_exit_code(ExitCode::STACK_BUFFER_OVERRUN, Some(2)).unwrap();
+        assert!(fail_fast.is_corruption());
+        assert!(!fail_fast.is_intentional());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            FailFastCode::FATAL_APP_EXIT.to_string(),
+            "FAST_FAIL_FATAL_APP_EXIT"
+        );
+        assert_eq!(FailFastCode::from_raw(1000).to_string(), "1000");
+    }
+}
@@ -0,0 +1,228 @@
+//! Exit code conventions of Windows installers and package managers.
+//!
+//! Installers use non-zero exit codes for outcomes that are not failures (i.e. a reboot is
+//! required to finish), and for failures that resolve on their own (i.e. another installation is
+//! already in progress). An [`Installer`] profile classifies its exit codes into an
+//! [`InstallOutcome`]:
+//!
+//! ```rust
+//! use proc_result::windows::ExitCode;
+//! use proc_result::windows::installer::{InstallOutcome, Installer};
+//!
+//! let outcome = Installer::Msiexec.classify(ExitCode::SUCCESS_REBOOT_REQUIRED);
+//! assert_eq!(outcome, InstallOutcome::SuccessWithReboot);
+//! assert!(outcome.is_success());
+//! ```
+
+use super::ExitCode;
+
+/// How an installer run ended, as far as provisioning is concerned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InstallOutcome {
+    /// The installation succeeded (or there was nothing to do).
+    Success,
+
+    /// The installation succeeded, but a reboot is required or was initiated to complete it.
+    SuccessWithReboot,
+
+    /// The installation failed, but may succeed if retried later.
+    Retryable,
+
+    /// The installation failed.
+    Fatal,
+}
+
+impl InstallOutcome {
+    /// Returns `true` if the installation succeeded, with or without a reboot.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self, Self::Success | Self::SuccessWithReboot)
+    }
+
+    /// Returns `true` if a reboot is required or was initiated.
+    #[must_use]
+    pub const fn is_reboot_required(&self) -> bool {
+        matches!(self, Self::SuccessWithReboot)
+    }
+}
+
+/// An installer or package manager with its own exit code conventions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Installer {
+    /// Windows Installer (`msiexec.exe`).
+    Msiexec,
+
+    /// Windows Package Manager (`winget.exe`).
+    ///
+    /// `winget` reports its own `APPINSTALLER_CLI_ERROR_*` `HRESULT`s (`0x8A15xxxx`).
+    Winget,
+
+    /// Chocolatey (`choco.exe`).
+    ///
+    /// Assumes `--use-enhanced-exit-codes`, under which `2` means there was nothing to do.
+    Chocolatey,
+}
+
+impl Installer {
+    /// Classifies an exit code reported by the installer.
+    ///
+    /// Exit code `0` is [`InstallOutcome::Success`], and unrecognized exit codes are
+    /// [`InstallOutcome::Fatal`].
+    #[must_use]
+    pub fn classify(&self, code: ExitCode) -> InstallOutcome {
+        if code.is_success() {
+            return InstallOutcome::Success;
+        }
+        let table = match self {
+            Self::Msiexec => MSIEXEC,
+            Self::Winget => WINGET,
+            Self::Chocolatey => CHOCOLATEY,
+        };
+        table
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map_or(InstallOutcome::Fatal, |(_, outcome)| *outcome)
+    }
+
+    /// Classifies an exit code reported by the installer when uninstalling a package.
+    ///
+    /// Like [`Installer::classify`], except that `msiexec` and Chocolatey report a product that
+    /// is not installed ([`ExitCode::UNKNOWN_PRODUCT`]) or already uninstalled
+    /// ([`ExitCode::PRODUCT_UNINSTALLED`]) as an error, which is [`InstallOutcome::Success`] for
+    /// an uninstallation, as there was nothing to do.
+    #[must_use]
+    pub fn classify_uninstall(&self, code: ExitCode) -> InstallOutcome {
+        match self {
+            Self::Msiexec | Self::Chocolatey
+                if code == ExitCode::UNKNOWN_PRODUCT || code == ExitCode::PRODUCT_UNINSTALLED =>
+            {
+                InstallOutcome::Success
+            }
+            _ => self.classify(code),
+        }
+    }
+}
+
+/// Exit codes of `msiexec.exe` that are not [`InstallOutcome::Fatal`].
+const MSIEXEC: &[(ExitCode, InstallOutcome)] = &[
+    (
+        ExitCode::SUCCESS_REBOOT_REQUIRED,
+        InstallOutcome::SuccessWithReboot,
+    ),
+    (
+        ExitCode::SUCCESS_REBOOT_INITIATED,
+        InstallOutcome::SuccessWithReboot,
+    ),
+    (ExitCode::INSTALL_ALREADY_RUNNING, InstallOutcome::Retryable),
+];
+
+/// Exit codes of `winget.exe` that are not [`InstallOutcome::Fatal`].
+const WINGET: &[(ExitCode, InstallOutcome)] = &[
+    // APPINSTALLER_CLI_ERROR_UPDATE_NOT_APPLICABLE
+    (ExitCode::from_raw(0x8A15_002B), InstallOutcome::Success),
+    // APPINSTALLER_CLI_ERROR_INSTALL_PACKAGE_IN_USE
+    (ExitCode::from_raw(0x8A15_0101), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_INSTALL_IN_PROGRESS
+    (ExitCode::from_raw(0x8A15_0102), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_FILE_IN_USE
+    (ExitCode::from_raw(0x8A15_0103), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_INSUFFICIENT_MEMORY
+    (ExitCode::from_raw(0x8A15_0106), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_NO_NETWORK
+    (ExitCode::from_raw(0x8A15_0107), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_REBOOT_REQUIRED_TO_FINISH
+    (
+        ExitCode::from_raw(0x8A15_0109),
+        InstallOutcome::SuccessWithReboot,
+    ),
+    // APPINSTALLER_CLI_ERROR_INSTALL_REBOOT_REQUIRED_FOR_INSTALL
+    (ExitCode::from_raw(0x8A15_010A), InstallOutcome::Retryable),
+    // APPINSTALLER_CLI_ERROR_INSTALL_REBOOT_INITIATED
+    (
+        ExitCode::from_raw(0x8A15_010B),
+        InstallOutcome::SuccessWithReboot,
+    ),
+    // APPINSTALLER_CLI_ERROR_INSTALL_ALREADY_INSTALLED
+    (ExitCode::from_raw(0x8A15_010D), InstallOutcome::Success),
+];
+
+/// Exit codes of `choco.exe` that are not [`InstallOutcome::Fatal`].
+const CHOCOLATEY: &[(ExitCode, InstallOutcome)] = &[
+    // Nothing to do (with --use-enhanced-exit-codes)
+    (ExitCode::from_raw(2), InstallOutcome::Success),
+    // ERROR_FAIL_NOACTION_REBOOT: a pending reboot blocks the installation
+    (ExitCode::from_raw(350), InstallOutcome::Retryable),
+    // ERROR_INSTALL_SUSPEND
+    (ExitCode::from_raw(1604), InstallOutcome::Retryable),
+    (ExitCode::INSTALL_ALREADY_RUNNING, InstallOutcome::Retryable),
+    (
+        ExitCode::SUCCESS_REBOOT_INITIATED,
+        InstallOutcome::SuccessWithReboot,
+    ),
+    (
+        ExitCode::SUCCESS_REBOOT_REQUIRED,
+        InstallOutcome::SuccessWithReboot,
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_msiexec() {
+        let classify = |code| Installer::Msiexec.classify(ExitCode::from_raw(code));
+        assert_eq!(classify(0), InstallOutcome::Success);
+        assert_eq!(classify(3010), InstallOutcome::SuccessWithReboot);
+        assert_eq!(classify(1641), InstallOutcome::SuccessWithReboot);
+        assert_eq!(classify(1602), InstallOutcome::Fatal);
+        assert_eq!(classify(1603), InstallOutcome::Fatal);
+        assert_eq!(classify(1618), InstallOutcome::Retryable);
+    }
+
+    #[test]
+    fn test_winget() {
+        let classify = |code| Installer::Winget.classify(ExitCode::from_raw(code));
+        assert_eq!(classify(0x8A15_010D), InstallOutcome::Success);
+        assert_eq!(classify(0x8A15_0109), InstallOutcome::SuccessWithReboot);
+        assert_eq!(classify(0x8A15_0102), InstallOutcome::Retryable);
+        assert_eq!(classify(0x8A15_010C), InstallOutcome::Fatal);
+    }
+
+    #[test]
+    fn test_chocolatey() {
+        let classify = |code| Installer::Chocolatey.classify(ExitCode::from_raw(code));
+        assert_eq!(classify(2), InstallOutcome::Success);
+        assert_eq!(classify(3010), InstallOutcome::SuccessWithReboot);
+        assert_eq!(classify(350), InstallOutcome::Retryable);
+        assert_eq!(classify(u32::MAX), InstallOutcome::Fatal);
+    }
+
+    #[test]
+    fn test_uninstall() {
+        for installer in [Installer::Msiexec, Installer::Chocolatey] {
+            for code in [ExitCode::UNKNOWN_PRODUCT, ExitCode::PRODUCT_UNINSTALLED] {
+                assert_eq!(installer.classify(code), InstallOutcome::Fatal);
+                assert_eq!(installer.classify_uninstall(code), InstallOutcome::Success);
+            }
+            assert_eq!(
+                installer.classify_uninstall(ExitCode::INSTALL_FAILURE),
+                InstallOutcome::Fatal
+            );
+        }
+        assert_eq!(
+            Installer::Winget.classify_uninstall(ExitCode::UNKNOWN_PRODUCT),
+            InstallOutcome::Fatal
+        );
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert!(InstallOutcome::SuccessWithReboot.is_success());
+        assert!(InstallOutcome::SuccessWithReboot.is_reboot_required());
+        assert!(!InstallOutcome::Retryable.is_success());
+        assert!(!InstallOutcome::Success.is_reboot_required());
+    }
+}
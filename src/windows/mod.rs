@@ -0,0 +1,26 @@
+//! Windows-specific exit codes and handling.
+//!
+//! This module is cross-platform, but on Windows, it provides conversions to/from
+//! [`std::process::ExitStatus`].
+
+mod exit_code;
+pub use exit_code::{ExitCode, ExitCodeFormat, FormattedExitCode, ParseExitCodeError};
+
+mod fail_fast;
+pub use fail_fast::{FailFast, FailFastCode, FailFastKind};
+
+pub mod installer;
+
+mod process_state;
+pub use process_state::{ProcessState, StillActive};
+
+mod service_exit_code;
+pub use service_exit_code::ServiceExitCode;
+
+pub mod shell;
+
+mod software_exception;
+pub use software_exception::{Runtime, SoftwareException, SoftwareExceptionKind};
+
+mod task_scheduler;
+pub use task_scheduler::{LastRunResult, SchedulerStatus};
@@ -0,0 +1,138 @@
+use core::fmt::Display;
+
+use super::ExitCode;
+
+/// The state of a Windows process, as read from its exit code.
+///
+/// `GetExitCodeProcess` reports [`ExitCode::STILL_ACTIVE`] (`259`) both for processes that are
+/// still running and for processes that really exited with `259`. This type keeps the cases
+/// apart, so that polled or recorded exit codes are not misread as a termination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProcessState {
+    /// The process is known to still be running.
+    Running,
+
+    /// The process is known to have terminated with an exit code.
+    Terminated {
+        /// The exit code of the process.
+        exit_code: ExitCode,
+    },
+
+    /// The process reported [`ExitCode::STILL_ACTIVE`], and is either running or exited with it.
+    Ambiguous,
+}
+
+impl ProcessState {
+    /// Creates a state from an exit code alone, such as a recorded `GetExitCodeProcess` result.
+    ///
+    /// [`ExitCode::STILL_ACTIVE`] is [`ProcessState::Ambiguous`]; everything else is terminated.
+    #[must_use]
+    pub const fn from_exit_code(exit_code: ExitCode) -> Self {
+        if exit_code.is_still_active() {
+            Self::Ambiguous
+        } else {
+            Self::Terminated { exit_code }
+        }
+    }
+
+    /// Creates a state from an exit code and whether the process handle was signaled.
+    ///
+    /// A process handle is signaled (i.e. `WaitForSingleObject` returns `WAIT_OBJECT_0`) only
+    /// once the process has terminated, which resolves the ambiguity of
+    /// [`ExitCode::STILL_ACTIVE`].
+    #[must_use]
+    pub const fn from_exit_code_and_wait(exit_code: ExitCode, signaled: bool) -> Self {
+        if signaled {
+            Self::Terminated { exit_code }
+        } else {
+            Self::Running
+        }
+    }
+
+    /// Returns the exit code if the process is known to have terminated, or `None` otherwise.
+    #[must_use]
+    pub const fn exit_code(&self) -> Option<ExitCode> {
+        match self {
+            Self::Terminated { exit_code } => Some(*exit_code),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the process is known to have terminated.
+    #[must_use]
+    pub const fn is_terminated(&self) -> bool {
+        matches!(self, Self::Terminated { .. })
+    }
+}
+
+impl From<ExitCode> for ProcessState {
+    fn from(exit_code: ExitCode) -> Self {
+        Self::from_exit_code(exit_code)
+    }
+}
+
+/// An error returned when a process is not known to have terminated.
+///
+/// Returned when constructing a [`ProcResult`](crate::ProcResult) from a
+/// [`ProcessState::Running`] or [`ProcessState::Ambiguous`] state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StillActive {
+    state: ProcessState,
+}
+
+impl StillActive {
+    pub(crate) const fn new(state: ProcessState) -> Self {
+        Self { state }
+    }
+
+    /// Returns the state that was not a termination.
+    #[must_use]
+    pub const fn state(&self) -> ProcessState {
+        self.state
+    }
+}
+
+impl Display for StillActive {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.state {
+            ProcessState::Running => f.write_str("process is still running"),
+            _ => f.write_str(
+                "exit code 259 (STILL_ACTIVE) is ambiguous: process may still be running",
+            ),
+        }
+    }
+}
+
+impl core::error::Error for StillActive {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_exit_code() {
+        assert_eq!(
+            ProcessState::from_exit_code(ExitCode::STILL_ACTIVE),
+            ProcessState::Ambiguous
+        );
+        assert_eq!(
+            ProcessState::from_exit_code(ExitCode::GENERAL_ERROR),
+            ProcessState::Terminated {
+                exit_code: ExitCode::GENERAL_ERROR
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_exit_code_and_wait() {
+        assert_eq!(
+            ProcessState::from_exit_code_and_wait(ExitCode::STILL_ACTIVE, false),
+            ProcessState::Running
+        );
+        assert_eq!(
+            ProcessState::from_exit_code_and_wait(ExitCode::STILL_ACTIVE, true).exit_code(),
+            Some(ExitCode::STILL_ACTIVE)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt::Display;
+
+use super::ExitCode;
+
+/// The exit code of a stopped Windows service.
+///
+/// A service reports its exit code through two fields of `SERVICE_STATUS`: `dwWin32ExitCode`,
+/// and, only if that is [`ExitCode::SERVICE_SPECIFIC_ERROR`], `dwServiceSpecificExitCode`. This
+/// type keeps both, and resolves them to a single [`ServiceExitCode::effective`] exit code:
+///
+/// ```rust
+/// use proc_result::ProcResult;
+/// use proc_result::windows::{ExitCode, ServiceExitCode};
+///
+/// let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 42);
+/// assert_eq!(code.effective(), ExitCode::from_raw(42));
+/// ```
+///
+/// A service-specific exit code is defined by the service, not by Windows, so converting to a
+/// [`ProcResult`](crate::ProcResult) keeps the Win32 exit code:
+///
+/// ```rust
+/// # use proc_result::ProcResult;
+/// # use proc_result::windows::{ExitCode, ServiceExitCode};
+/// let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 42);
+/// assert_eq!(ProcResult::from(code), ProcResult::Windows(ExitCode::SERVICE_SPECIFIC_ERROR));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceExitCode {
+    win32_exit_code: ExitCode,
+    service_specific_exit_code: u32,
+}
+
+impl ServiceExitCode {
+    /// Creates a new `ServiceExitCode` from the two `SERVICE_STATUS` fields.
+    #[must_use]
+    pub const fn new(win32_exit_code: ExitCode, service_specific_exit_code: u32) -> Self {
+        Self {
+            win32_exit_code,
+            service_specific_exit_code,
+        }
+    }
+
+    /// Returns the `dwWin32ExitCode` field.
+    #[must_use]
+    pub const fn win32_exit_code(&self) -> ExitCode {
+        self.win32_exit_code
+    }
+
+    /// Returns the `dwServiceSpecificExitCode` field.
+    ///
+    /// This field is only meaningful if [`ServiceExitCode::is_service_specific`] is `true`.
+    #[must_use]
+    pub const fn service_specific_exit_code(&self) -> u32 {
+        self.service_specific_exit_code
+    }
+
+    /// Returns `true` if the service reported a service-specific exit code.
+    #[must_use]
+    pub const fn is_service_specific(&self) -> bool {
+        self.win32_exit_code.to_raw() == ExitCode::SERVICE_SPECIFIC_ERROR.to_raw()
+    }
+
+    /// Returns the exit code that describes why the service stopped.
+    ///
+    /// This is the service-specific exit code if one was reported, and the Win32 exit code
+    /// otherwise. A service-specific exit code of `0` contradicts the reported error, so in that
+    /// case [`ExitCode::SERVICE_SPECIFIC_ERROR`] itself is returned.
+    #[must_use]
+    pub const fn effective(&self) -> ExitCode {
+        if self.is_service_specific() && self.service_specific_exit_code != 0 {
+            ExitCode::from_raw(self.service_specific_exit_code)
+        } else {
+            self.win32_exit_code
+        }
+    }
+
+    /// Returns `true` if the service stopped successfully.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.effective().is_success()
+    }
+}
+
+impl Display for ServiceExitCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.is_service_specific() {
+            write!(
+                f,
+                "service-specific error {}",
+                self.service_specific_exit_code
+            )
+        } else {
+            self.win32_exit_code.fmt(f)
+        }
+    }
+}
+
+impl From<ExitCode> for ServiceExitCode {
+    fn from(win32_exit_code: ExitCode) -> Self {
+        Self::new(win32_exit_code, 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_win32() {
+        let code = ServiceExitCode::new(ExitCode::ACCESS_DENIED, 42);
+        assert!(!code.is_service_specific());
+        assert_eq!(code.effective(), ExitCode::ACCESS_DENIED);
+        assert!(!code.is_success());
+    }
+
+    #[test]
+    fn test_effective_service_specific() {
+        let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 42);
+        assert!(code.is_service_specific());
+        assert_eq!(code.effective(), ExitCode::from_raw(42));
+    }
+
+    #[test]
+    fn test_effective_service_specific_zero() {
+        let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 0);
+        assert_eq!(code.effective(), ExitCode::SERVICE_SPECIFIC_ERROR);
+        assert!(!code.is_success());
+    }
+
+    #[test]
+    fn test_into_result() {
+        use crate::ProcResult;
+
+        let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 2);
+        assert_eq!(
+            ProcResult::from(code),
+            ProcResult::Windows(ExitCode::SERVICE_SPECIFIC_ERROR)
+        );
+        let code = ServiceExitCode::from(ExitCode::ACCESS_DENIED);
+        assert_eq!(
+            ProcResult::from(code),
+            ProcResult::Windows(ExitCode::ACCESS_DENIED)
+        );
+    }
+
+    #[test]
+    fn test_is_success() {
+        assert!(ServiceExitCode::default().is_success());
+    }
+
+    #[test]
+    fn test_display() {
+        let code = ServiceExitCode::new(ExitCode::SERVICE_SPECIFIC_ERROR, 42);
+        assert_eq!(code.to_string(), "service-specific error 42");
+        let code = ServiceExitCode::from(ExitCode::ACCESS_DENIED);
+        assert_eq!(code.to_string(), "5");
+    }
+}
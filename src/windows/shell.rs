@@ -0,0 +1,306 @@
+//! How `cmd.exe` and PowerShell transform the exit code of what they run.
+//!
+//! A program run through `cmd /c` or `pwsh -Command` does not report its exit code directly; the
+//! shell reports its own, derived from the exit code of the last command and how the script
+//! ended. [`CmdExit`] and [`PowerShellExit`] model that derivation, and can read a shell's exit
+//! code back.
+//!
+//! ```rust
+//! use proc_result::windows::ExitCode;
+//! use proc_result::windows::shell::{PowerShellEdition, PowerShellExit, PowerShellMode};
+//!
+//! // `pwsh -Command "Get-Item missing.txt"` fails, and PowerShell reports `1`.
+//! let failed = PowerShellExit::Completed { succeeded: false };
+//! let outer = failed.exit_code(PowerShellEdition::Core, PowerShellMode::Command);
+//! assert_eq!(outer, ExitCode::GENERAL_ERROR);
+//! ```
+
+use super::ExitCode;
+
+/// How a command or script run by `cmd.exe /c` ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CmdExit {
+    /// The last command ran, and its exit code was left in `ERRORLEVEL`.
+    Command(ExitCode),
+
+    /// The script ran `exit /b N` (or `exit N`), setting `ERRORLEVEL` to `N`.
+    Exit(i32),
+
+    /// The command was not found, which `cmd.exe` reports as
+    /// [`ExitCode::COMMAND_NOT_RECOGNIZED`].
+    NotRecognized,
+}
+
+impl CmdExit {
+    /// Returns the exit code `cmd.exe` itself reports.
+    ///
+    /// `ERRORLEVEL` is a signed 32-bit value, so `exit /b -1` is reported as `0xFFFFFFFF`.
+    #[allow(clippy::cast_sign_loss)]
+    #[must_use]
+    pub const fn exit_code(&self) -> ExitCode {
+        match self {
+            Self::Command(code) => *code,
+            Self::Exit(errorlevel) => ExitCode::from_raw(*errorlevel as u32),
+            Self::NotRecognized => ExitCode::COMMAND_NOT_RECOGNIZED,
+        }
+    }
+
+    /// Reads the exit code reported by `cmd.exe`.
+    ///
+    /// [`ExitCode::COMMAND_NOT_RECOGNIZED`] is read as [`CmdExit::NotRecognized`]; anything else
+    /// is read as [`CmdExit::Command`], as an `exit /b` cannot be told apart from a command.
+    #[must_use]
+    pub const fn from_exit_code(code: ExitCode) -> Self {
+        if code.to_raw() == ExitCode::COMMAND_NOT_RECOGNIZED.to_raw() {
+            Self::NotRecognized
+        } else {
+            Self::Command(code)
+        }
+    }
+
+    /// Returns the value `%ERRORLEVEL%` expands to for an exit code.
+    ///
+    /// For example, [`ExitCode::ACCESS_VIOLATION`] is `-1073741819`.
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    pub const fn errorlevel(code: ExitCode) -> i32 {
+        code.to_raw() as i32
+    }
+}
+
+/// Which PowerShell ran a script, as reported by `$PSEdition`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerShellEdition {
+    /// Windows PowerShell 5.1 (`powershell.exe`).
+    Desktop,
+
+    /// PowerShell 7 and later (`pwsh`).
+    Core,
+}
+
+impl PowerShellEdition {
+    /// Returns the exit code the edition reports when it cannot run the script at all.
+    ///
+    /// Windows PowerShell reports `-196608` (`0xFFFD0000`), and PowerShell 7 reports `64`.
+    #[must_use]
+    pub const fn host_error(&self) -> ExitCode {
+        match self {
+            Self::Desktop => ExitCode::from_raw(0xFFFD_0000),
+            Self::Core => ExitCode::from_raw(64),
+        }
+    }
+}
+
+/// How PowerShell was asked to run a script.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerShellMode {
+    /// `pwsh -Command` (the default of `powershell.exe` when no mode is given).
+    #[default]
+    Command,
+
+    /// `pwsh -File` (the default of `pwsh` when no mode is given).
+    File,
+}
+
+/// How a script run by PowerShell ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerShellExit {
+    /// The script ran to the end without calling `exit`.
+    Completed {
+        /// The value of `$?`, i.e. whether the last statement succeeded.
+        succeeded: bool,
+    },
+
+    /// The script called `exit N`.
+    Exit(i32),
+
+    /// The script raised an unhandled terminating error, i.e. with `throw`.
+    Threw,
+
+    /// PowerShell could not start the script, i.e. because the `-File` does not exist.
+    ///
+    /// Reported as [`PowerShellEdition::host_error`].
+    HostError,
+}
+
+impl PowerShellExit {
+    /// Creates a [`PowerShellExit::Exit`] from the value passed to `exit`.
+    ///
+    /// PowerShell passes the value to the operating system as a 32-bit integer, so larger values
+    /// wrap; for example, `exit 4294967296` is reported as `0`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[must_use]
+    pub const fn exit(value: i64) -> Self {
+        Self::Exit(value as i32)
+    }
+
+    /// Returns the exit code PowerShell itself reports.
+    ///
+    /// A failed last statement is reported as `1` with `-Command`, but ignored with `-File`.
+    #[allow(clippy::cast_sign_loss)]
+    #[must_use]
+    pub const fn exit_code(&self, edition: PowerShellEdition, mode: PowerShellMode) -> ExitCode {
+        match (self, mode) {
+            (Self::Completed { succeeded: false }, PowerShellMode::Command) | (Self::Threw, _) => {
+                ExitCode::GENERAL_ERROR
+            }
+            (Self::Completed { .. }, _) => ExitCode::SUCCESS,
+            (Self::Exit(value), _) => ExitCode::from_raw(*value as u32),
+            (Self::HostError, _) => edition.host_error(),
+        }
+    }
+
+    /// Reads the exit code reported by PowerShell.
+    ///
+    /// `0` and `1` are read as how the script completed (or [`PowerShellExit::Threw`] with
+    /// `-File`, which does not report failed statements), the edition's
+    /// [`host_error`](PowerShellEdition::host_error) as [`PowerShellExit::HostError`], and
+    /// anything else as [`PowerShellExit::Exit`]. With PowerShell 7, `exit 64` is therefore read
+    /// as a host error.
+    ///
+    /// `-1` (`0xFFFFFFFF`) is read as `Exit(-1)`. PowerShell reports an unhandled `throw` as `1`
+    /// in both modes, and a script that reports one as `-1` itself, i.e. with a `trap` that calls
+    /// `exit -1`, produces exactly the same code as `exit -1`, so the two cannot be told apart.
+    #[must_use]
+    pub const fn from_exit_code(
+        code: ExitCode,
+        edition: PowerShellEdition,
+        mode: PowerShellMode,
+    ) -> Self {
+        match (code.to_raw(), mode) {
+            (0, _) => Self::Completed { succeeded: true },
+            (1, PowerShellMode::Command) => Self::Completed { succeeded: false },
+            (1, PowerShellMode::File) => Self::Threw,
+            (raw, _) if raw == edition.host_error().to_raw() => Self::HostError,
+            _ => Self::Exit(Self::last_exit_code(code)),
+        }
+    }
+
+    /// Returns the value `$LASTEXITCODE` is set to for an exit code.
+    ///
+    /// `$LASTEXITCODE` is a signed 32-bit value; for example, [`ExitCode::ACCESS_VIOLATION`] is
+    /// `-1073741819`.
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    pub const fn last_exit_code(code: ExitCode) -> i32 {
+        code.to_raw() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmd_exit_code() {
+        assert_eq!(
+            CmdExit::Command(ExitCode::ACCESS_VIOLATION).exit_code(),
+            ExitCode::ACCESS_VIOLATION
+        );
+        assert_eq!(CmdExit::Exit(-1).exit_code().to_raw(), u32::MAX);
+        assert_eq!(
+            CmdExit::NotRecognized.exit_code(),
+            ExitCode::COMMAND_NOT_RECOGNIZED
+        );
+    }
+
+    #[test]
+    fn test_cmd_from_exit_code() {
+        assert_eq!(
+            CmdExit::from_exit_code(ExitCode::from_raw(9009)),
+            CmdExit::NotRecognized
+        );
+        assert_eq!(
+            CmdExit::from_exit_code(ExitCode::GENERAL_ERROR),
+            CmdExit::Command(ExitCode::GENERAL_ERROR)
+        );
+        assert_eq!(
+            CmdExit::errorlevel(ExitCode::ACCESS_VIOLATION),
+            -1_073_741_819
+        );
+    }
+
+    #[test]
+    fn test_powershell_exit_code() {
+        use PowerShellEdition::{Core, Desktop};
+
+        let failed = PowerShellExit::Completed { succeeded: false };
+        assert_eq!(
+            failed.exit_code(Core, PowerShellMode::Command),
+            ExitCode::GENERAL_ERROR
+        );
+        assert_eq!(
+            failed.exit_code(Core, PowerShellMode::File),
+            ExitCode::SUCCESS
+        );
+        assert_eq!(
+            PowerShellExit::Threw.exit_code(Desktop, PowerShellMode::File),
+            ExitCode::GENERAL_ERROR
+        );
+        assert_eq!(
+            PowerShellExit::Exit(-1)
+                .exit_code(Core, PowerShellMode::Command)
+                .to_raw(),
+            u32::MAX
+        );
+        assert_eq!(
+            PowerShellExit::HostError
+                .exit_code(Desktop, PowerShellMode::File)
+                .to_raw(),
+            0xFFFD_0000
+        );
+        assert_eq!(
+            PowerShellExit::HostError
+                .exit_code(Core, PowerShellMode::File)
+                .to_raw(),
+            64
+        );
+    }
+
+    #[test]
+    fn test_powershell_exit_wraps() {
+        assert_eq!(PowerShellExit::exit(4_294_967_296), PowerShellExit::Exit(0));
+        assert_eq!(
+            PowerShellExit::exit(3_221_225_477),
+            PowerShellExit::Exit(-1_073_741_819)
+        );
+    }
+
+    #[test]
+    fn test_powershell_from_exit_code() {
+        use PowerShellEdition::{Core, Desktop};
+
+        let read = |raw, edition| {
+            PowerShellExit::from_exit_code(ExitCode::from_raw(raw), edition, PowerShellMode::File)
+        };
+        assert_eq!(read(1, Core), PowerShellExit::Threw);
+        assert_eq!(read(0xFFFD_0000, Desktop), PowerShellExit::HostError);
+        assert_eq!(read(64, Core), PowerShellExit::HostError);
+        assert_eq!(read(64, Desktop), PowerShellExit::Exit(64));
+        assert_eq!(read(0xFFFD_0000, Core), PowerShellExit::Exit(-196_608));
+        assert_eq!(read(42, Core), PowerShellExit::Exit(42));
+    }
+
+    #[test]
+    fn test_powershell_from_exit_code_minus_one() {
+        // `throw` is reported as `1`; `-1` is indistinguishable from `exit -1`.
+        for edition in [PowerShellEdition::Desktop, PowerShellEdition::Core] {
+            for mode in [PowerShellMode::Command, PowerShellMode::File] {
+                let code = ExitCode::from_raw(0xFFFF_FFFF);
+                assert_eq!(
+                    PowerShellExit::from_exit_code(code, edition, mode),
+                    PowerShellExit::Exit(-1)
+                );
+                assert_eq!(PowerShellExit::Exit(-1).exit_code(edition, mode), code);
+                assert_eq!(
+                    PowerShellExit::Threw.exit_code(edition, mode),
+                    ExitCode::GENERAL_ERROR
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use core::fmt::Display;
+
+use super::ExitCode;
+
+/// A language runtime that raises its own user-mode exceptions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Runtime {
+    /// The .NET common language runtime.
+    DotNet,
+
+    /// The Microsoft Visual C++ runtime.
+    Cpp,
+
+    /// The Delphi (Object Pascal) runtime.
+    Delphi,
+}
+
+impl Display for Runtime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::DotNet => ".NET",
+            Self::Cpp => "C++",
+            Self::Delphi => "Delphi",
+        })
+    }
+}
+
+/// How a runtime terminated the process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SoftwareExceptionKind {
+    /// An exception thrown by the program was not caught.
+    UnhandledException,
+
+    /// The runtime itself failed, i.e. due to internal corruption or a forced fail-fast.
+    FatalError,
+}
+
+/// A termination caused by a language runtime's own exception code.
+///
+/// Runtimes implement exceptions on top of Windows structured exception handling, each with its
+/// own exception code; when such an exception is not caught, the process terminates with it:
+///
+/// ```rust
+/// use proc_result::windows::{ExitCode, Runtime};
+///
+/// let exception = ExitCode::from_raw(0xE043_4352).software_exception().unwrap();
+/// assert_eq!(exception.runtime(), Runtime::DotNet);
+/// assert!(exception.is_unhandled());
+/// assert_eq!(exception.to_string(), "unhandled .NET exception");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoftwareException {
+    runtime: Runtime,
+    kind: SoftwareExceptionKind,
+}
+
+impl SoftwareException {
+    /// Returns the software exception an exit code represents, if any.
+    #[must_use]
+    pub const fn from_exit_code(code: ExitCode) -> Option<Self> {
+        let (runtime, kind) = match code.to_raw() {
+            0xE043_4352 | 0xE043_4F4D => {
+                (Runtime::DotNet, SoftwareExceptionKind::UnhandledException)
+            }
+            0x8013_1506 => (Runtime::DotNet, SoftwareExceptionKind::FatalError),
+            0xE06D_7363 => (Runtime::Cpp, SoftwareExceptionKind::UnhandledException),
+            0x0EED_FADE => (Runtime::Delphi, SoftwareExceptionKind::UnhandledException),
+            _ => return None,
+        };
+        Some(Self { runtime, kind })
+    }
+
+    /// Returns the runtime that raised the exception.
+    #[must_use]
+    pub const fn runtime(&self) -> Runtime {
+        self.runtime
+    }
+
+    /// Returns how the runtime terminated the process.
+    #[must_use]
+    pub const fn kind(&self) -> SoftwareExceptionKind {
+        self.kind
+    }
+
+    /// Returns `true` if the process terminated because an exception was not caught.
+    #[must_use]
+    pub const fn is_unhandled(&self) -> bool {
+        matches!(self.kind, SoftwareExceptionKind::UnhandledException)
+    }
+}
+
+impl Display for SoftwareException {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.kind {
+            SoftwareExceptionKind::UnhandledException => {
+                write!(f, "unhandled {} exception", self.runtime)
+            }
+            SoftwareExceptionKind::FatalError => write!(f, "fatal {} runtime error", self.runtime),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_exit_code() {
+        let exception = SoftwareException::from_exit_code(ExitCode::CPP_EXCEPTION).unwrap();
+        assert_eq!(exception.runtime(), Runtime::Cpp);
+        assert!(exception.is_unhandled());
+
+        let exception =
+            SoftwareException::from_exit_code(ExitCode::CLR_EXECUTION_ENGINE_ERROR).unwrap();
+        assert_eq!(exception.runtime(), Runtime::DotNet);
+        assert_eq!(exception.kind(), SoftwareExceptionKind::FatalError);
+        assert!(!exception.is_unhandled());
+
+        let exception = SoftwareException::from_exit_code(ExitCode::DELPHI_EXCEPTION).unwrap();
+        assert_eq!(exception.runtime(), Runtime::Delphi);
+
+        assert_eq!(
+            SoftwareException::from_exit_code(ExitCode::ACCESS_VIOLATION),
+            None
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let exception = SoftwareException::from_exit_code(ExitCode::CPP_EXCEPTION).unwrap();
+        assert_eq!(exception.to_string(), "unhandled C++ exception");
+
+        let exception =
+            SoftwareException::from_exit_code(ExitCode::CLR_EXECUTION_ENGINE_ERROR).unwrap();
+        assert_eq!(exception.to_string(), "fatal .NET runtime error");
+    }
+}
@@ -0,0 +1,359 @@
+use core::fmt::Display;
+
+use super::ExitCode;
+use crate::ProcResult;
+
+/// A Task Scheduler status code (`SCHED_S_*` or `SCHED_E_*`).
+///
+/// These values are reported in place of an exit code when the scheduler itself has something to
+/// say about a task, i.e. that it is still running or has never run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct SchedulerStatus(u32);
+
+impl SchedulerStatus {
+    /// The task is ready to run at its next scheduled time (`SCHED_S_TASK_READY`).
+    pub const TASK_READY: Self = Self(0x0004_1300);
+
+    /// The task is currently running (`SCHED_S_TASK_RUNNING`).
+    pub const TASK_RUNNING: Self = Self(0x0004_1301);
+
+    /// The task will not run at the scheduled times because it has been disabled
+    /// (`SCHED_S_TASK_DISABLED`).
+    pub const TASK_DISABLED: Self = Self(0x0004_1302);
+
+    /// The task has not yet run (`SCHED_S_TASK_HAS_NOT_RUN`).
+    pub const TASK_HAS_NOT_RUN: Self = Self(0x0004_1303);
+
+    /// There are no more runs scheduled for this task (`SCHED_S_TASK_NO_MORE_RUNS`).
+    pub const TASK_NO_MORE_RUNS: Self = Self(0x0004_1304);
+
+    /// One or more of the properties needed to run this task have not been set
+    /// (`SCHED_S_TASK_NOT_SCHEDULED`).
+    pub const TASK_NOT_SCHEDULED: Self = Self(0x0004_1305);
+
+    /// The last run of the task was terminated by the user (`SCHED_S_TASK_TERMINATED`).
+    pub const TASK_TERMINATED: Self = Self(0x0004_1306);
+
+    /// Either the task has no triggers, or the existing triggers are disabled or not set
+    /// (`SCHED_S_TASK_NO_VALID_TRIGGERS`).
+    pub const TASK_NO_VALID_TRIGGERS: Self = Self(0x0004_1307);
+
+    /// Event triggers do not have set run times (`SCHED_S_EVENT_TRIGGER`).
+    pub const EVENT_TRIGGER: Self = Self(0x0004_1308);
+
+    /// The task is registered, but not all specified triggers will start the task
+    /// (`SCHED_S_SOME_TRIGGERS_FAILED`).
+    pub const SOME_TRIGGERS_FAILED: Self = Self(0x0004_131B);
+
+    /// The task is registered, but may fail to start; batch logon privilege needs to be enabled
+    /// for the task principal (`SCHED_S_BATCH_LOGON_PROBLEM`).
+    pub const BATCH_LOGON_PROBLEM: Self = Self(0x0004_131C);
+
+    /// The task is queued to run (`SCHED_S_TASK_QUEUED`).
+    pub const TASK_QUEUED: Self = Self(0x0004_1325);
+
+    /// The task is not running (`SCHED_E_TASK_NOT_RUNNING`).
+    pub const TASK_NOT_RUNNING: Self = Self(0x8004_130B);
+
+    /// The Task Scheduler service is not installed on this computer
+    /// (`SCHED_E_SERVICE_NOT_INSTALLED`).
+    pub const SERVICE_NOT_INSTALLED: Self = Self(0x8004_130C);
+
+    /// The task object could not be opened (`SCHED_E_CANNOT_OPEN_TASK`).
+    pub const CANNOT_OPEN_TASK: Self = Self(0x8004_130D);
+
+    /// The account information could not be set (`SCHED_E_ACCOUNT_INFORMATION_NOT_SET`).
+    pub const ACCOUNT_INFORMATION_NOT_SET: Self = Self(0x8004_130F);
+
+    /// The Task Scheduler service is not running (`SCHED_E_SERVICE_NOT_RUNNING`).
+    pub const SERVICE_NOT_RUNNING: Self = Self(0x8004_1315);
+
+    /// An instance of this task is already running (`SCHED_E_ALREADY_RUNNING`).
+    pub const ALREADY_RUNNING: Self = Self(0x8004_131F);
+
+    /// The task will not run because the user is not logged on (`SCHED_E_USER_NOT_LOGGED_ON`).
+    pub const USER_NOT_LOGGED_ON: Self = Self(0x8004_1320);
+
+    /// The Task Scheduler service is not available (`SCHED_E_SERVICE_NOT_AVAILABLE`).
+    pub const SERVICE_NOT_AVAILABLE: Self = Self(0x8004_1322);
+
+    /// The Task Scheduler service is too busy to handle the request
+    /// (`SCHED_E_SERVICE_TOO_BUSY`).
+    pub const SERVICE_TOO_BUSY: Self = Self(0x8004_1323);
+
+    /// The task was attempted to run, but failed due to a constraint
+    /// (`SCHED_E_TASK_ATTEMPTED`).
+    pub const TASK_ATTEMPTED: Self = Self(0x8004_1324);
+
+    /// The task is disabled (`SCHED_E_TASK_DISABLED`).
+    pub const TASK_DISABLED_ERROR: Self = Self(0x8004_1326);
+
+    /// The task can only be started on demand (`SCHED_E_START_ON_DEMAND`).
+    pub const START_ON_DEMAND: Self = Self(0x8004_1328);
+
+    /// The operator or administrator has refused the request.
+    ///
+    /// Corresponds to `HRESULT_FROM_WIN32(ERROR_REQUEST_REFUSED)`, which the scheduler reports
+    /// when a task was stopped or not started because of its settings.
+    pub const REQUEST_REFUSED: Self = Self(0x8007_10E0);
+
+    /// Creates a new `SchedulerStatus` from the underlying `u32` code.
+    #[must_use]
+    pub const fn from_raw(code: u32) -> Self {
+        Self(code)
+    }
+
+    /// Returns the underlying `u32` code.
+    #[must_use]
+    pub const fn to_raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns `true` if the code is a Task Scheduler status code.
+    ///
+    /// This is the case for the `SCHED_S_*` and `SCHED_E_*` ranges (`0x0004_13xx` and
+    /// `0x8004_13xx`), and for [`SchedulerStatus::REQUEST_REFUSED`].
+    #[must_use]
+    pub const fn is_scheduler_code(code: u32) -> bool {
+        code & 0x7FFF_FF00 == 0x0004_1300 || code == Self::REQUEST_REFUSED.0
+    }
+
+    /// Returns `true` if the status is an error (`SCHED_E_*`) rather than a state (`SCHED_S_*`).
+    #[must_use]
+    pub const fn is_error(&self) -> bool {
+        self.0 & 0x8000_0000 != 0
+    }
+
+    /// Returns the symbolic name of the status, if known.
+    #[must_use]
+    pub fn name(&self) -> Option<&'static str> {
+        SCHEDULER_NAMES
+            .iter()
+            .find(|(status, _)| status == self)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl Display for SchedulerStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "0x{:08X}", self.0),
+        }
+    }
+}
+
+/// Symbolic names of known scheduler status codes.
+const SCHEDULER_NAMES: &[(SchedulerStatus, &str)] = &[
+    (SchedulerStatus::TASK_READY, "SCHED_S_TASK_READY"),
+    (SchedulerStatus::TASK_RUNNING, "SCHED_S_TASK_RUNNING"),
+    (SchedulerStatus::TASK_DISABLED, "SCHED_S_TASK_DISABLED"),
+    (
+        SchedulerStatus::TASK_HAS_NOT_RUN,
+        "SCHED_S_TASK_HAS_NOT_RUN",
+    ),
+    (
+        SchedulerStatus::TASK_NO_MORE_RUNS,
+        "SCHED_S_TASK_NO_MORE_RUNS",
+    ),
+    (
+        SchedulerStatus::TASK_NOT_SCHEDULED,
+        "SCHED_S_TASK_NOT_SCHEDULED",
+    ),
+    (SchedulerStatus::TASK_TERMINATED, "SCHED_S_TASK_TERMINATED"),
+    (
+        SchedulerStatus::TASK_NO_VALID_TRIGGERS,
+        "SCHED_S_TASK_NO_VALID_TRIGGERS",
+    ),
+    (SchedulerStatus::EVENT_TRIGGER, "SCHED_S_EVENT_TRIGGER"),
+    (
+        SchedulerStatus::SOME_TRIGGERS_FAILED,
+        "SCHED_S_SOME_TRIGGERS_FAILED",
+    ),
+    (
+        SchedulerStatus::BATCH_LOGON_PROBLEM,
+        "SCHED_S_BATCH_LOGON_PROBLEM",
+    ),
+    (SchedulerStatus::TASK_QUEUED, "SCHED_S_TASK_QUEUED"),
+    (
+        SchedulerStatus::TASK_NOT_RUNNING,
+        "SCHED_E_TASK_NOT_RUNNING",
+    ),
+    (
+        SchedulerStatus::SERVICE_NOT_INSTALLED,
+        "SCHED_E_SERVICE_NOT_INSTALLED",
+    ),
+    (
+        SchedulerStatus::CANNOT_OPEN_TASK,
+        "SCHED_E_CANNOT_OPEN_TASK",
+    ),
+    (
+        SchedulerStatus::ACCOUNT_INFORMATION_NOT_SET,
+        "SCHED_E_ACCOUNT_INFORMATION_NOT_SET",
+    ),
+    (
+        SchedulerStatus::SERVICE_NOT_RUNNING,
+        "SCHED_E_SERVICE_NOT_RUNNING",
+    ),
+    (SchedulerStatus::ALREADY_RUNNING, "SCHED_E_ALREADY_RUNNING"),
+    (
+        SchedulerStatus::USER_NOT_LOGGED_ON,
+        "SCHED_E_USER_NOT_LOGGED_ON",
+    ),
+    (
+        SchedulerStatus::SERVICE_NOT_AVAILABLE,
+        "SCHED_E_SERVICE_NOT_AVAILABLE",
+    ),
+    (
+        SchedulerStatus::SERVICE_TOO_BUSY,
+        "SCHED_E_SERVICE_TOO_BUSY",
+    ),
+    (SchedulerStatus::TASK_ATTEMPTED, "SCHED_E_TASK_ATTEMPTED"),
+    (
+        SchedulerStatus::TASK_DISABLED_ERROR,
+        "SCHED_E_TASK_DISABLED",
+    ),
+    (SchedulerStatus::START_ON_DEMAND, "SCHED_E_START_ON_DEMAND"),
+    (SchedulerStatus::REQUEST_REFUSED, "ERROR_REQUEST_REFUSED"),
+];
+
+/// A decoded Task Scheduler "Last Run Result".
+///
+/// The scheduler reports either a status of its own, or the exit code of the task's last run, in
+/// the same field. This type separates the two:
+///
+/// ```rust
+/// use proc_result::windows::{ExitCode, LastRunResult, SchedulerStatus};
+///
+/// let result = LastRunResult::from_raw(0x0004_1301);
+/// assert_eq!(result, LastRunResult::Scheduler(SchedulerStatus::TASK_RUNNING));
+/// assert_eq!(result.exit_code(), None);
+///
+/// let result = LastRunResult::from_raw(2);
+/// assert_eq!(result.exit_code(), Some(ExitCode::FILE_NOT_FOUND));
+/// ```
+///
+/// A task that really exits with a code in a scheduler range is indistinguishable from the
+/// scheduler status, and is decoded as the latter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LastRunResult {
+    /// The scheduler reported a status; the task's exit code is not available.
+    Scheduler(SchedulerStatus),
+
+    /// The task ran and exited with an exit code.
+    Exited(ExitCode),
+}
+
+impl LastRunResult {
+    /// Decodes a "Last Run Result" value.
+    #[must_use]
+    pub const fn from_raw(code: u32) -> Self {
+        if SchedulerStatus::is_scheduler_code(code) {
+            Self::Scheduler(SchedulerStatus::from_raw(code))
+        } else {
+            Self::Exited(ExitCode::from_raw(code))
+        }
+    }
+
+    /// Returns the underlying "Last Run Result" value.
+    #[must_use]
+    pub const fn to_raw(&self) -> u32 {
+        match self {
+            Self::Scheduler(status) => status.to_raw(),
+            Self::Exited(code) => code.to_raw(),
+        }
+    }
+
+    /// Returns the task's exit code, or `None` if the scheduler reported a status instead.
+    #[must_use]
+    pub const fn exit_code(&self) -> Option<ExitCode> {
+        match self {
+            Self::Exited(code) => Some(*code),
+            Self::Scheduler(_) => None,
+        }
+    }
+
+    /// Returns the scheduler status, or `None` if the task's exit code was reported instead.
+    #[must_use]
+    pub const fn scheduler_status(&self) -> Option<SchedulerStatus> {
+        match self {
+            Self::Scheduler(status) => Some(*status),
+            Self::Exited(_) => None,
+        }
+    }
+
+    /// Returns the result of the task's last run, or `None` if no exit code was reported.
+    #[must_use]
+    pub const fn proc_result(&self) -> Option<ProcResult> {
+        match self {
+            Self::Exited(code) => Some(ProcResult::Windows(*code)),
+            Self::Scheduler(_) => None,
+        }
+    }
+}
+
+impl From<u32> for LastRunResult {
+    fn from(code: u32) -> Self {
+        Self::from_raw(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_raw_scheduler() {
+        for (raw, status) in [
+            (0x0004_1301, SchedulerStatus::TASK_RUNNING),
+            (0x0004_1303, SchedulerStatus::TASK_HAS_NOT_RUN),
+            (0x0004_1306, SchedulerStatus::TASK_TERMINATED),
+            (0x8004_131F, SchedulerStatus::ALREADY_RUNNING),
+            (0x8007_10E0, SchedulerStatus::REQUEST_REFUSED),
+        ] {
+            assert_eq!(
+                LastRunResult::from_raw(raw),
+                LastRunResult::Scheduler(status)
+            );
+            assert_eq!(LastRunResult::from_raw(raw).to_raw(), raw);
+        }
+    }
+
+    #[test]
+    fn test_from_raw_exited() {
+        let result = LastRunResult::from_raw(0xC000_0005);
+        assert_eq!(result, LastRunResult::Exited(ExitCode::ACCESS_VIOLATION));
+        assert_eq!(
+            result.proc_result(),
+            Some(ProcResult::Windows(ExitCode::ACCESS_VIOLATION))
+        );
+        assert_eq!(
+            LastRunResult::from_raw(0).exit_code(),
+            Some(ExitCode::SUCCESS)
+        );
+    }
+
+    #[test]
+    fn test_unknown_scheduler_code() {
+        let result = LastRunResult::from_raw(0x8004_13FF);
+        let status = result.scheduler_status().unwrap();
+        assert!(status.is_error());
+        assert_eq!(status.name(), None);
+        assert_eq!(status.to_string(), "0x800413FF");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            SchedulerStatus::TASK_HAS_NOT_RUN.to_string(),
+            "SCHED_S_TASK_HAS_NOT_RUN"
+        );
+        assert!(!SchedulerStatus::TASK_HAS_NOT_RUN.is_error());
+    }
+}